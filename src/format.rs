@@ -29,6 +29,8 @@ fn info_type_to_string(info: &InfoType) -> String {
             .map(|b| format!("{:?}", b))
             .collect::<Vec<_>>()
             .join(" or "),
+        InfoType::Data(data) => data.to_string(),
+        InfoType::Item(item) => item.to_command(),
     }
 }
 
@@ -1,18 +1,25 @@
 pub mod format;
 pub mod index;
 pub mod loader;
+pub mod nbt;
 pub mod results;
 pub mod runner;
 pub mod spatial;
 pub mod test_spec;
+#[cfg(test)]
+mod test_support;
 pub mod timeline;
 pub mod traits;
 pub mod utils;
 
 // Re-export main types for convenience
 pub use runner::{TestRunConfig, TestRunner};
-pub use traits::{BlockPos, FlintAdapter, FlintPlayer, FlintWorld, ServerInfo};
+pub use traits::{
+    BlockPos, CommandResult, EntityId, EntityPos, FlintAdapter, FlintEntity, FlintPlayer,
+    FlintWorld, FluidState, Interaction, ScheduledTick, ServerInfo, SetBlockFlags, WorldEvent,
+};
 
 // Re-export flint-core types commonly used with this library
 pub use crate::loader::TestLoader;
-pub use crate::test_spec::{Block, Item, PlayerSlot, TestSpec};
+pub use crate::nbt::Nbt;
+pub use crate::test_spec::{Block, Item, PlayerSlot, TestSpec, WorldConfig, WorldOption};
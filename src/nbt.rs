@@ -0,0 +1,155 @@
+//! Structured NBT-like data used for block entities.
+//!
+//! Values map one-to-one onto JSON, so test specs can write block entity data
+//! directly, e.g. `{"Items": [{"id": "minecraft:stone", "count": 1}]}`.
+//! Adapters convert between `Nbt` and their native representation.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// NBT-like value tree
+///
+/// JSON has no distinction between byte, short, int and long, so all integer tags map to `Int`
+/// and all floating point tags map to `Double`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Nbt {
+    Bool(bool),
+    Int(i64),
+    Double(f64),
+    String(String),
+    List(Vec<Nbt>),
+    Compound(BTreeMap<String, Nbt>),
+}
+
+impl Nbt {
+    /// Create an empty compound.
+    pub fn compound() -> Self {
+        Nbt::Compound(BTreeMap::new())
+    }
+
+    /// Get a child of a compound by key.
+    pub fn get(&self, key: &str) -> Option<&Nbt> {
+        match self {
+            Nbt::Compound(map) => map.get(key),
+            _ => None,
+        }
+    }
+
+    /// Check if `actual` contains everything in `self` (partial match).
+    ///
+    /// Follows the semantics of `/execute if data`:
+    /// - compounds match if every expected key matches in `actual`, extra keys are ignored
+    /// - lists match if every expected element matches some actual element;
+    ///   an empty expected list only matches an empty list
+    /// - numbers are compared by value, so `1` matches `1.0` and `true` matches `1`
+    pub fn matches(&self, actual: &Nbt) -> bool {
+        match (self, actual) {
+            (Nbt::Compound(expected), Nbt::Compound(actual)) => expected
+                .iter()
+                .all(|(key, value)| actual.get(key).is_some_and(|a| value.matches(a))),
+            (Nbt::List(expected), Nbt::List(actual)) => {
+                if expected.is_empty() {
+                    actual.is_empty()
+                } else {
+                    expected.iter().all(|e| actual.iter().any(|a| e.matches(a)))
+                }
+            }
+            (Nbt::String(expected), Nbt::String(actual)) => expected == actual,
+            (expected, actual) => match (expected.as_f64(), actual.as_f64()) {
+                (Some(e), Some(a)) => e == a,
+                _ => false,
+            },
+        }
+    }
+
+    /// Numeric value of a bool or number, NBT stores booleans as bytes.
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Nbt::Bool(b) => Some(if *b { 1.0 } else { 0.0 }),
+            Nbt::Int(i) => Some(*i as f64),
+            Nbt::Double(d) => Some(*d),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for Nbt {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Nbt::Bool(a), Nbt::Bool(b)) => a == b,
+            (Nbt::Int(a), Nbt::Int(b)) => a == b,
+            // Bitwise comparison keeps `Eq` lawful for NaN
+            (Nbt::Double(a), Nbt::Double(b)) => a.to_bits() == b.to_bits(),
+            (Nbt::String(a), Nbt::String(b)) => a == b,
+            (Nbt::List(a), Nbt::List(b)) => a == b,
+            (Nbt::Compound(a), Nbt::Compound(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Nbt {}
+
+impl Display for Nbt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let json = serde_json::to_string(self).map_err(|_| std::fmt::Error)?;
+        f.write_str(&json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> Nbt {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_parse_variants() {
+        assert_eq!(parse("true"), Nbt::Bool(true));
+        assert_eq!(parse("42"), Nbt::Int(42));
+        assert_eq!(parse("0.5"), Nbt::Double(0.5));
+        assert_eq!(parse(r#""text""#), Nbt::String("text".to_string()));
+        assert!(matches!(parse("[1, 2]"), Nbt::List(l) if l.len() == 2));
+        assert!(matches!(parse(r#"{"a": 1}"#), Nbt::Compound(_)));
+    }
+
+    #[test]
+    fn test_partial_compound_match() {
+        let actual = parse(r#"{"CustomName": "Bob", "Lock": "", "x": 3}"#);
+        assert!(parse(r#"{"CustomName": "Bob"}"#).matches(&actual));
+        assert!(parse("{}").matches(&actual));
+        assert!(!parse(r#"{"CustomName": "Alice"}"#).matches(&actual));
+        assert!(!parse(r#"{"Missing": 1}"#).matches(&actual));
+    }
+
+    #[test]
+    fn test_partial_list_match() {
+        let actual = parse(
+            r#"{"Items": [
+                {"Slot": 0, "id": "minecraft:stone", "count": 64},
+                {"Slot": 4, "id": "minecraft:dirt", "count": 1}
+            ]}"#,
+        );
+        assert!(parse(r#"{"Items": [{"id": "minecraft:dirt"}]}"#).matches(&actual));
+        assert!(!parse(r#"{"Items": [{"id": "minecraft:sand"}]}"#).matches(&actual));
+        assert!(!parse(r#"{"Items": []}"#).matches(&actual));
+        assert!(parse(r#"{"Items": []}"#).matches(&parse(r#"{"Items": []}"#)));
+    }
+
+    #[test]
+    fn test_numeric_match() {
+        assert!(parse("1").matches(&parse("1.0")));
+        assert!(parse("true").matches(&parse("1")));
+        assert!(!parse("1").matches(&parse(r#""1""#)));
+    }
+
+    #[test]
+    fn test_display_as_json() {
+        let nbt = parse(r#"{"b": [1, "x"], "a": true}"#);
+        assert_eq!(nbt.to_string(), r#"{"a":true,"b":[1,"x"]}"#);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::format;
+use crate::nbt::Nbt;
 use crate::results::AssertionResult::Failure;
-use crate::test_spec::Block;
+use crate::test_spec::{Block, Item};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
@@ -19,13 +20,17 @@ pub enum InfoType {
     String(String),
     Block(Block),
     Blocks(Vec<Block>),
+    Data(Nbt),
+    Item(Item),
 }
 
 impl InfoType {
     pub fn get_string(&self) -> Option<String> {
         match self {
             InfoType::String(s) => Some(s.clone()),
-            InfoType::Block(_) | InfoType::Blocks(_) => None,
+            InfoType::Block(_) | InfoType::Blocks(_) | InfoType::Data(_) | InfoType::Item(_) => {
+                None
+            }
         }
     }
 }
@@ -39,6 +44,8 @@ impl From<InfoType> for String {
                 .map(|b| b.to_command())
                 .collect::<Vec<_>>()
                 .join(" or "),
+            InfoType::Data(data) => data.to_string(),
+            InfoType::Item(item) => item.to_command(),
         }
     }
 }
@@ -52,6 +59,8 @@ impl From<&InfoType> for String {
                 .map(|b| b.to_command())
                 .collect::<Vec<_>>()
                 .join(" or "),
+            InfoType::Data(data) => data.to_string(),
+            InfoType::Item(item) => item.to_command(),
         }
     }
 }
@@ -2,13 +2,21 @@
 //!
 //! The `TestRunner` loads tests and executes them against a server adapter.
 
+use crate::nbt::Nbt;
 use crate::results::{
     ActionOutcome, AssertFailure, AssertionResult, InfoType, TestResult, TestSummary,
 };
-use crate::test_spec::{ActionType, Item, PlayerSlot};
+use crate::test_spec::{
+    ActionType, BreakMode, EventMatcher, GameRuleValue, Hand, Item, NumberMatcher, PlayerConfig,
+    PlayerSlot, SetupSpec, StatusEffect, Weather,
+};
 use crate::timeline::TimelineAggregate;
-use crate::traits::{FlintAdapter, FlintPlayer, FlintWorld};
+use crate::traits::{
+    BlockPos, EntityId, EntityPos, FlintAdapter, FlintEntity, FlintPlayer, FlintWorld, FluidState,
+    Interaction, ScheduledTick, WorldEvent,
+};
 use crate::{Block, TestSpec};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -35,6 +43,137 @@ impl Default for TestRunConfig {
     }
 }
 
+/// Players of a running test, created on demand
+///
+/// The implicit player (no name) and every named player from `setup.players`
+/// are independent players in the same world. The runner only asks for declared names.
+#[derive(Default)]
+struct Players {
+    implicit: Option<Box<dyn FlintPlayer>>,
+    named: BTreeMap<String, Box<dyn FlintPlayer>>,
+}
+
+impl Players {
+    /// Get a player by name (`None` = implicit player), creating it if needed
+    fn get(&mut self, world: &mut dyn FlintWorld, name: Option<&str>) -> &mut Box<dyn FlintPlayer> {
+        match name {
+            None => self.implicit.get_or_insert_with(|| world.create_player()),
+            Some(name) => self
+                .named
+                .entry(name.to_string())
+                .or_insert_with(|| world.create_player()),
+        }
+    }
+}
+
+/// World settings changed by a test, with their values from before the first change
+#[derive(Default)]
+struct WorldSettings {
+    day_time: Option<u64>,
+    weather: Option<Weather>,
+    game_rules: BTreeMap<String, GameRuleValue>,
+    /// Original biome per changed 4x4x4 biome cell, keyed by the cell's minimum corner
+    biomes: BTreeMap<BlockPos, String>,
+}
+
+impl WorldSettings {
+    /// Apply the time, weather and game rules of the test setup.
+    ///
+    /// Returns the failure reason for the first setting that could not be applied.
+    fn apply_setup(&mut self, world: &mut dyn FlintWorld, setup: &SetupSpec) -> Result<(), String> {
+        if let Some(time) = setup.time
+            && !self.set_day_time(world, time)
+        {
+            return Err("Adapter does not support world time".to_string());
+        }
+        if let Some(weather) = setup.weather
+            && !self.set_weather(world, weather)
+        {
+            return Err("Adapter does not support weather".to_string());
+        }
+        for (rule, value) in &setup.game_rules {
+            if !self.set_game_rule(world, rule, value) {
+                return Err(game_rule_error(rule, value));
+            }
+        }
+        Ok(())
+    }
+
+    fn set_day_time(&mut self, world: &mut dyn FlintWorld, time: u64) -> bool {
+        // A fresh world starts at time 0
+        self.day_time
+            .get_or_insert_with(|| world.day_time().unwrap_or(0));
+        world.set_day_time(time)
+    }
+
+    fn set_weather(&mut self, world: &mut dyn FlintWorld, weather: Weather) -> bool {
+        self.weather
+            .get_or_insert_with(|| world.weather().unwrap_or_default());
+        world.set_weather(weather)
+    }
+
+    fn set_game_rule(
+        &mut self,
+        world: &mut dyn FlintWorld,
+        rule: &str,
+        value: &GameRuleValue,
+    ) -> bool {
+        // Rules the adapter cannot read back are left as set by the test
+        if !self.game_rules.contains_key(rule)
+            && let Some(original) = world.game_rule(rule)
+        {
+            self.game_rules.insert(rule.to_string(), original);
+        }
+        world.set_game_rule(rule, value)
+    }
+
+    fn set_biome(
+        &mut self,
+        world: &mut dyn FlintWorld,
+        region: [BlockPos; 2],
+        biome: &str,
+    ) -> bool {
+        let [min, max] = ordered_region(region);
+        // Biomes are stored per 4x4x4 cell, remember every touched cell once
+        for x in min[0].div_euclid(4)..=max[0].div_euclid(4) {
+            for y in min[1].div_euclid(4)..=max[1].div_euclid(4) {
+                for z in min[2].div_euclid(4)..=max[2].div_euclid(4) {
+                    let cell = [x * 4, y * 4, z * 4];
+                    if !self.biomes.contains_key(&cell)
+                        && let Some(original) = world.get_biome(cell)
+                    {
+                        self.biomes.insert(cell, original);
+                    }
+                }
+            }
+        }
+        world.set_biome([min, max], biome)
+    }
+
+    /// Restore every changed setting to its original value
+    fn restore(&mut self, world: &mut dyn FlintWorld) {
+        if let Some(time) = self.day_time.take() {
+            world.set_day_time(time);
+        }
+        if let Some(weather) = self.weather.take() {
+            world.set_weather(weather);
+        }
+        for (rule, value) in std::mem::take(&mut self.game_rules) {
+            world.set_game_rule(&rule, &value);
+        }
+        for (cell, biome) in std::mem::take(&mut self.biomes) {
+            world.set_biome([cell, cell.map(|c| c + 3)], &biome);
+        }
+    }
+}
+
+/// Mutable state of a running test
+#[derive(Default)]
+struct TestState {
+    players: Players,
+    world_settings: WorldSettings,
+}
+
 /// Test execution engine
 pub struct TestRunner<A: FlintAdapter> {
     adapter: Arc<A>,
@@ -49,8 +188,40 @@ impl<A: FlintAdapter> TestRunner<A> {
     /// Run a single test
     pub fn run_test(&self, spec: &TestSpec) -> TestResult {
         let start_time = Instant::now();
-        let mut world = self.adapter.create_test_world();
+        let world_config = spec
+            .setup
+            .as_ref()
+            .map(|setup| setup.world.clone())
+            .unwrap_or_default();
+        if let Some(option) = world_config
+            .requested_options()
+            .into_iter()
+            .find(|option| !self.adapter.supports_world_option(*option))
+        {
+            let mut result = TestResult::new(&spec.name);
+            result.minecraft_ids = spec.minecraft_ids.clone();
+            return result
+                .with_failure_reason(format!("Adapter does not support world option {}", option));
+        }
+        let mut world = self.adapter.create_test_world(&world_config);
+        let mut state = TestState::default();
+
+        let mut result = self.run_timeline(&mut *world, spec, &mut state);
+
+        // Undo world setting and biome changes in case the world is shared with other tests
+        state.world_settings.restore(&mut *world);
 
+        result.execution_time_ms = start_time.elapsed().as_millis() as u64;
+        result
+    }
+
+    /// Apply the test setup and execute its timeline tick by tick
+    fn run_timeline(
+        &self,
+        world: &mut dyn FlintWorld,
+        spec: &TestSpec,
+        state: &mut TestState,
+    ) -> TestResult {
         // Build timeline for single test (no offset)
         let tests_with_offsets = vec![(spec.clone(), [0i32, 0, 0])];
         let timeline = TimelineAggregate::from_tests(&tests_with_offsets);
@@ -58,30 +229,77 @@ impl<A: FlintAdapter> TestRunner<A> {
         let mut result = TestResult::new(&spec.name);
         result.minecraft_ids = spec.minecraft_ids.clone();
 
-        // Player is created on demand when player actions are used
-        let mut player: Option<Box<dyn FlintPlayer>> = None;
-
-        // Initialize player from config if present (advanced mode)
-        if let Some(setup) = &spec.setup
-            && let Some(player_config) = setup.player.as_ref()
+        // Named players must be declared, they would otherwise join without their config
+        let declared = |name: &String| {
+            let setup = spec.setup.as_ref();
+            setup.is_some_and(|setup| setup.players.contains_key(name))
+        };
+        if let Some(name) = spec
+            .timeline
+            .iter()
+            .filter_map(|entry| entry.player.as_ref())
+            .find(|name| !declared(name))
         {
-            let p = player.get_or_insert_with(|| world.create_player());
+            return result.with_failure_reason(format!(
+                "Unknown player '{}', declare it in setup.players",
+                name
+            ));
+        }
+
+        if let Some(setup) = &spec.setup {
+            // Apply world settings before players join
+            if let Err(reason) = state.world_settings.apply_setup(world, setup) {
+                return result.with_failure_reason(reason);
+            }
 
-            // Set initial inventory
-            for (slot_name, item) in &player_config.inventory {
-                p.set_slot(*slot_name, Some(item));
+            // Initialize players from config if present (advanced mode)
+            let configs = setup.player.iter().map(|config| (None, config)).chain(
+                setup
+                    .players
+                    .iter()
+                    .map(|(name, config)| (Some(name.as_str()), config)),
+            );
+            for (name, config) in configs {
+                let p = state.players.get(world, name);
+                if let Err(feature) = apply_player_config(&mut **p, config) {
+                    return result
+                        .with_failure_reason(format!("Adapter does not support {}", feature));
+                }
             }
+        }
 
-            // Set initial hotbar selection
-            p.select_hotbar(player_config.selected_hotbar);
+        // Record events for tests asserting them, covering every asserted region
+        let event_regions = spec
+            .timeline
+            .iter()
+            .filter_map(|entry| match &entry.action_type {
+                ActionType::AssertEvents { region, .. } => Some(ordered_region(*region)),
+                _ => None,
+            });
+        if let Some(region) = event_regions.reduce(|a, b| {
+            [
+                std::array::from_fn(|i| a[0][i].min(b[0][i])),
+                std::array::from_fn(|i| a[1][i].max(b[1][i])),
+            ]
+        }) && !world.capture_events(region)
+        {
+            return result.with_failure_reason("Adapter does not support event capture");
         }
 
         // Execute timeline tick by tick
         for tick in 0..=timeline.max_tick {
             // Execute actions for this tick
             if let Some(actions) = timeline.timeline.get(&tick) {
-                for (_test_idx, entry, _value_idx) in actions.iter() {
-                    match self.execute_action(&mut *world, &mut player, &entry.action_type, tick) {
+                for (test_idx, entry, _value_idx) in actions.iter() {
+                    let origin = tests_with_offsets[*test_idx].1;
+                    match self.execute_action(
+                        world,
+                        state,
+                        entry.player.as_deref(),
+                        &entry.action_type,
+                        origin,
+                        tick,
+                    ) {
                         ActionOutcome::Action => {}
                         ActionOutcome::AssertPassed => {
                             result.add_assertion(AssertionResult::Success(tick));
@@ -90,7 +308,6 @@ impl<A: FlintAdapter> TestRunner<A> {
                             result.add_assertion(AssertionResult::Failure(fail));
                             result.success = false;
                             result.total_ticks = tick;
-                            result.execution_time_ms = start_time.elapsed().as_millis() as u64;
                             return result;
                         }
                     }
@@ -102,7 +319,6 @@ impl<A: FlintAdapter> TestRunner<A> {
         }
 
         result.total_ticks = timeline.max_tick;
-        result.execution_time_ms = start_time.elapsed().as_millis() as u64;
         result
     }
 
@@ -116,27 +332,42 @@ impl<A: FlintAdapter> TestRunner<A> {
     fn execute_action(
         &self,
         world: &mut dyn FlintWorld,
-        player: &mut Option<Box<dyn FlintPlayer>>,
+        state: &mut TestState,
+        who: Option<&str>,
         action: &ActionType,
+        origin: BlockPos,
         _tick: u32,
     ) -> ActionOutcome {
         match action {
-            ActionType::Place { pos, block } => {
+            ActionType::Place { pos, block, mode } => {
                 let pos = [pos[0], pos[1], pos[2]];
-                world.set_block(pos, block);
+                if let Some(tag) = block.tag() {
+                    return placed_tag(tag, pos, _tick);
+                }
+                if !world.set_block_with(pos, block, (*mode).into()) {
+                    return unsupported("silent block placement", pos, _tick);
+                }
                 ActionOutcome::Action
             }
 
-            ActionType::PlaceEach { blocks } => {
+            ActionType::PlaceEach { blocks, mode } => {
                 for placement in blocks {
                     let pos = [placement.pos[0], placement.pos[1], placement.pos[2]];
-                    world.set_block(pos, &placement.block);
+                    if let Some(tag) = placement.block.tag() {
+                        return placed_tag(tag, pos, _tick);
+                    }
+                    if !world.set_block_with(pos, &placement.block, (*mode).into()) {
+                        return unsupported("silent block placement", pos, _tick);
+                    }
                 }
                 ActionOutcome::Action
             }
 
-            ActionType::Fill { region, with } => {
-                // Flint handles fill by iterating set_block
+            ActionType::Fill { region, with, mode } => {
+                if let Some(tag) = with.tag() {
+                    return placed_tag(tag, region[0], _tick);
+                }
+                // Flint handles fill by iterating set_block_with
                 // Handle potentially inverted coordinates
                 let min_x = region[0][0].min(region[1][0]);
                 let max_x = region[0][0].max(region[1][0]);
@@ -148,33 +379,271 @@ impl<A: FlintAdapter> TestRunner<A> {
                 for x in min_x..=max_x {
                     for y in min_y..=max_y {
                         for z in min_z..=max_z {
-                            world.set_block([x, y, z], with);
+                            if !world.set_block_with([x, y, z], with, (*mode).into()) {
+                                return unsupported("silent block placement", [x, y, z], _tick);
+                            }
                         }
                     }
                 }
                 ActionOutcome::Action
             }
 
-            ActionType::Remove { pos } => {
+            ActionType::Remove { pos, mode } => {
                 let pos = [pos[0], pos[1], pos[2]];
                 let air = Block {
                     id: "minecraft:air".to_string(),
                     properties: Default::default(),
                 };
-                world.set_block(pos, &air);
+                if !world.set_block_with(pos, &air, (*mode).into()) {
+                    return unsupported("silent block placement", pos, _tick);
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::SetBlockData { pos, data } => {
+                let pos = [pos[0], pos[1], pos[2]];
+                if !world.set_block_data(pos, data) {
+                    return ActionOutcome::AssertFailed(AssertFailure::new(
+                        _tick,
+                        format!("Cannot set block entity data at {:?}", pos),
+                        pos,
+                        InfoType::String("a block entity".to_string()),
+                        InfoType::Block(world.get_block(pos)),
+                    ));
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::AssertPower {
+                pos,
+                face,
+                strong,
+                power,
+            } => {
+                let pos = [pos[0], pos[1], pos[2]];
+                let actual = match face {
+                    Some(face) if *strong => world.direct_signal(pos, face),
+                    _ => world.indirect_signal(pos, face.as_ref()),
+                };
+                let Some(actual) = actual else {
+                    return unsupported("redstone signal queries", pos, _tick);
+                };
+                if !power.matches(actual as f64) {
+                    let kind = match face {
+                        Some(face) if *strong => format!("strong signal through {:?} face", face),
+                        Some(face) => format!("signal received through {:?} face", face),
+                        None => "received signal".to_string(),
+                    };
+                    return ActionOutcome::AssertFailed(AssertFailure::new(
+                        _tick,
+                        format!(
+                            "Power mismatch at {:?} ({}): expected {}, got {}",
+                            pos, kind, power, actual
+                        ),
+                        pos,
+                        InfoType::String(format!("power {}", power)),
+                        InfoType::String(format!("power {}", actual)),
+                    ));
+                }
+                ActionOutcome::AssertPassed
+            }
+
+            ActionType::PlaceFluid {
+                pos,
+                fluid,
+                amount,
+                falling,
+            } => {
+                // Validation rejects `amount` together with `falling`
+                let state = match (amount, falling) {
+                    (Some(amount), _) => FluidState {
+                        id: namespaced(fluid),
+                        amount: *amount,
+                        falling: false,
+                        source: false,
+                    },
+                    (None, true) => FluidState {
+                        id: namespaced(fluid),
+                        amount: 8,
+                        falling: true,
+                        source: false,
+                    },
+                    (None, false) => FluidState::source(namespaced(fluid)),
+                };
+                if !world.set_fluid(*pos, &state) {
+                    return unsupported("fluid placement", *pos, _tick);
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::AssertFluid {
+                pos,
+                fluid,
+                amount,
+                falling,
+                source,
+            } => {
+                let Some(actual) = world.get_fluid(*pos) else {
+                    return unsupported("fluid queries", *pos, _tick);
+                };
+                if !fluid_matches(&actual, fluid, amount.as_ref(), *falling, *source) {
+                    let mut expected = namespaced(fluid);
+                    if let Some(amount) = amount {
+                        expected.push_str(&format!(" amount {}", amount));
+                    }
+                    if let Some(falling) = falling {
+                        expected.push_str(&format!(" falling={}", falling));
+                    }
+                    if let Some(source) = source {
+                        expected.push_str(&format!(" source={}", source));
+                    }
+                    let actual = format!(
+                        "{} amount {} falling={} source={}",
+                        actual.id, actual.amount, actual.falling, actual.source
+                    );
+                    return ActionOutcome::AssertFailed(AssertFailure::new(
+                        _tick,
+                        format!(
+                            "Fluid mismatch at {:?}: expected {}, got {}",
+                            pos, expected, actual
+                        ),
+                        *pos,
+                        InfoType::String(expected),
+                        InfoType::String(actual),
+                    ));
+                }
+                ActionOutcome::AssertPassed
+            }
+
+            ActionType::AssertLight { pos, block, sky } => {
+                let checks = [
+                    ("block light", block, world.block_light(*pos)),
+                    ("sky light", sky, world.sky_light(*pos)),
+                ];
+                for (kind, expected, actual) in checks {
+                    let Some(expected) = expected else {
+                        continue;
+                    };
+                    let Some(actual) = actual else {
+                        return unsupported("light queries", *pos, _tick);
+                    };
+                    if !expected.matches(actual as f64) {
+                        return ActionOutcome::AssertFailed(AssertFailure::new(
+                            _tick,
+                            format!(
+                                "Light mismatch at {:?} ({}): expected {}, got {}",
+                                pos, kind, expected, actual
+                            ),
+                            *pos,
+                            InfoType::String(format!("{} {}", kind, expected)),
+                            InfoType::String(format!("{} {}", kind, actual)),
+                        ));
+                    }
+                }
+                ActionOutcome::AssertPassed
+            }
+
+            ActionType::SetContainer { pos, items } => {
+                let pos = [pos[0], pos[1], pos[2]];
+                let Some(size) = world.container_size(pos) else {
+                    return no_container(world, pos, _tick);
+                };
+                if let Some(entry) = items.iter().find(|entry| entry.slot >= size) {
+                    return slot_out_of_range("set", pos, size, entry.slot, _tick);
+                }
+                for slot in 0..size {
+                    if !world.set_container_slot(pos, slot, None) {
+                        return slot_not_set(pos, slot, &Item::empty(), _tick);
+                    }
+                }
+                for entry in items {
+                    if !world.set_container_slot(pos, entry.slot, Some(&entry.item)) {
+                        return slot_not_set(pos, entry.slot, &entry.item, _tick);
+                    }
+                }
                 ActionOutcome::Action
             }
 
+            ActionType::AssertContainer {
+                pos,
+                slots,
+                contains,
+            } => {
+                let pos = [pos[0], pos[1], pos[2]];
+                let Some(size) = world.container_size(pos) else {
+                    return no_container(world, pos, _tick);
+                };
+                if let Some(entry) = slots.iter().find(|entry| entry.slot >= size) {
+                    return slot_out_of_range("check", pos, size, entry.slot, _tick);
+                }
+                let items: Vec<Option<Item>> = (0..size)
+                    .map(|slot| world.get_container_slot(pos, slot))
+                    .collect();
+
+                for entry in slots {
+                    let expected = &entry.item;
+                    let actual = items.get(entry.slot).cloned().flatten();
+                    if !item_matches(expected, actual.as_ref()) {
+                        let actual = actual.unwrap_or_else(Item::empty);
+                        return ActionOutcome::AssertFailed(AssertFailure::new(
+                            _tick,
+                            format!(
+                                "Container slot {} mismatch at {:?}: expected '{}', got '{}'",
+                                entry.slot,
+                                pos,
+                                expected.to_command(),
+                                actual.to_command(),
+                            ),
+                            pos,
+                            InfoType::Item(expected.clone()),
+                            InfoType::Item(actual),
+                        ));
+                    }
+                }
+
+                for expected in contains {
+                    let total: u32 = items
+                        .iter()
+                        .flatten()
+                        .filter(|item| {
+                            ids_match(&item.id, &expected.id) && components_match(expected, item)
+                        })
+                        .map(|item| item.count as u32)
+                        .sum();
+                    if total < expected.count as u32 {
+                        return ActionOutcome::AssertFailed(AssertFailure::new(
+                            _tick,
+                            format!(
+                                "Container at {:?}: expected at least {} of '{}', found {}",
+                                pos, expected.count, expected.id, total
+                            ),
+                            pos,
+                            InfoType::Item(expected.clone()),
+                            InfoType::String(format!("{} {}", expected.id, total)),
+                        ));
+                    }
+                }
+                ActionOutcome::AssertPassed
+            }
+
             ActionType::Assert { checks } => {
                 for check in checks {
                     let pos = [check.pos[0], check.pos[1], check.pos[2]];
                     let actual = world.get_block(pos);
                     let expected_blocks = check.is.to_vec();
 
-                    if !expected_blocks
-                        .iter()
-                        .any(|expected| block_matches(&actual, expected))
-                    {
+                    let mut matched = false;
+                    for expected in &expected_blocks {
+                        match self.block_matches(&actual, expected) {
+                            Ok(true) => {
+                                matched = true;
+                                break;
+                            }
+                            Ok(false) => {}
+                            Err(tag) => return unknown_tag(tag, pos, _tick),
+                        }
+                    }
+                    if !matched {
                         let expected_str = expected_blocks
                             .iter()
                             .map(|b| b.to_command())
@@ -194,31 +663,150 @@ impl<A: FlintAdapter> TestRunner<A> {
                             actual: InfoType::Block(actual),
                         });
                     }
+
+                    if let Some(expected_data) = &check.data {
+                        let actual_data = world.get_block_data(pos);
+                        if !actual_data
+                            .as_ref()
+                            .is_some_and(|actual| expected_data.matches(actual))
+                        {
+                            let actual_info = match actual_data {
+                                Some(data) => InfoType::Data(data),
+                                None => InfoType::String("no block entity data".to_string()),
+                            };
+                            return ActionOutcome::AssertFailed(AssertFailure::new(
+                                _tick,
+                                format!(
+                                    "Block data mismatch at {:?}: expected '{}', got '{}'",
+                                    pos,
+                                    expected_data,
+                                    String::from(&actual_info),
+                                ),
+                                pos,
+                                InfoType::Data(expected_data.clone()),
+                                actual_info,
+                            ));
+                        }
+                    }
                 }
                 ActionOutcome::AssertPassed
             }
 
-            ActionType::UseItemOn { pos, face, item } => {
+            ActionType::UseItemOn {
+                pos,
+                face,
+                item,
+                hit,
+                hand,
+                sneaking,
+            } => {
                 // Create player on demand if not already created
-                let p = player.get_or_insert_with(|| world.create_player());
+                let p = state.players.get(world, who);
+                let pos = [pos[0], pos[1], pos[2]];
+
+                // Simple mode: if item is specified, put it in the used hand
+                if let Some(item_id) = item {
+                    hold_item(&mut **p, item_id, *hand);
+                }
+
+                let interaction = Interaction {
+                    hit: hit.unwrap_or_else(|| face.center()),
+                    hand: *hand,
+                    sneaking: *sneaking,
+                };
+                p.use_item_on(pos, face, &interaction);
+                ActionOutcome::Action
+            }
+
+            ActionType::BreakBlock {
+                pos,
+                face,
+                mode,
+                item,
+            } => {
+                let p = state.players.get(world, who);
                 let pos = [pos[0], pos[1], pos[2]];
 
                 // Simple mode: if item is specified, set it in hotbar1 and select it
                 if let Some(item_id) = item {
-                    let item = Item::new(item_id);
-                    p.set_slot(PlayerSlot::Hotbar1, Some(&item));
-                    p.select_hotbar(1);
+                    hold_item(&mut **p, item_id, Hand::Main);
                 }
 
-                p.use_item_on(pos, face);
+                // Whether the block actually broke is checked by later assertions
+                let supported = match mode {
+                    BreakMode::Instant => p.break_block(pos),
+                    BreakMode::Survival => p.start_mining(pos, face),
+                };
+                match (supported, mode) {
+                    (Some(_), _) => ActionOutcome::Action,
+                    (None, BreakMode::Instant) => unsupported("breaking blocks", pos, _tick),
+                    (None, BreakMode::Survival) => unsupported("survival mining", pos, _tick),
+                }
+            }
+
+            ActionType::UseItem { hand, ticks, item } => {
+                let p = state.players.get(world, who);
+                if let Some(item_id) = item {
+                    hold_item(&mut **p, item_id, *hand);
+                }
+                if !p.use_item(*hand, *ticks) {
+                    return unsupported("using items", player_block_pos(&**p), _tick);
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::UseItemOnEntity {
+                entity,
+                pos,
+                tolerance,
+                hand,
+                item,
+            } => {
+                let target = match find_entity(world, entity.as_deref(), *pos, *tolerance, _tick) {
+                    Ok(target) => target,
+                    Err(outcome) => return *outcome,
+                };
+                let p = state.players.get(world, who);
+                if let Some(item_id) = item {
+                    hold_item(&mut **p, item_id, *hand);
+                }
+                if !p.use_item_on_entity(target, *hand) {
+                    return unsupported("entity interaction", pos.map(|c| c.floor() as i32), _tick);
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::AttackEntity {
+                entity,
+                pos,
+                tolerance,
+                item,
+            } => {
+                let target = match find_entity(world, entity.as_deref(), *pos, *tolerance, _tick) {
+                    Ok(target) => target,
+                    Err(outcome) => return *outcome,
+                };
+                let p = state.players.get(world, who);
+                if let Some(item_id) = item {
+                    hold_item(&mut **p, item_id, Hand::Main);
+                }
+                if !p.attack_entity(target) {
+                    return unsupported("entity interaction", pos.map(|c| c.floor() as i32), _tick);
+                }
                 ActionOutcome::Action
             }
 
-            ActionType::SetSlot { slot, item, count } => {
+            ActionType::SetSlot {
+                slot,
+                item,
+                count,
+                components,
+            } => {
                 // Create player on demand if not already created
-                let p = player.get_or_insert_with(|| world.create_player());
+                let p = state.players.get(world, who);
                 if let Some(item_id) = item {
-                    let item = Item::with_count(item_id, *count);
+                    let mut item = Item::with_count(item_id, *count);
+                    item.components = components.clone();
                     p.set_slot(*slot, Some(&item));
                 } else {
                     p.set_slot(*slot, None);
@@ -228,45 +816,2453 @@ impl<A: FlintAdapter> TestRunner<A> {
 
             ActionType::SelectHotbar { slot } => {
                 // Create player on demand if not already created
-                let p = player.get_or_insert_with(|| world.create_player());
+                let p = state.players.get(world, who);
                 p.select_hotbar(*slot);
                 ActionOutcome::Action
             }
-        }
-    }
-}
 
-/// Check if actual block matches expected.
-fn block_matches(actual: &Block, expected: &Block) -> bool {
-    // Check block ID
-    if actual.id != expected.id {
-        // Also try without minecraft: prefix
-        let expected_id = if expected.id.starts_with("minecraft:") {
-            &expected.id[10..]
-        } else {
-            &expected.id
-        };
-        let actual_id = if actual.id.starts_with("minecraft:") {
-            &actual.id[10..]
-        } else {
-            &actual.id
-        };
-        if actual_id != expected_id {
-            return false;
-        }
-    }
+            ActionType::Teleport { pos, yaw, pitch } => {
+                let p = state.players.get(world, who);
+                if !p.teleport(*pos, *yaw, *pitch) {
+                    return unsupported("player positioning", pos.map(|c| c.floor() as i32), _tick);
+                }
+                ActionOutcome::Action
+            }
 
-    // Check properties if specified in expected
-    for (key, expected_value) in &expected.properties {
-        if let Some(actual_value) = actual.properties.get(key) {
-            if actual_value != expected_value {
-                return false;
+            ActionType::Move { by } => {
+                let p = state.players.get(world, who);
+                if !p.move_by(*by) {
+                    return unsupported("player movement", player_block_pos(&**p), _tick);
+                }
+                ActionOutcome::Action
             }
-        } else {
-            // Property expected but not found in actual block - this is a mismatch
-            return false;
-        }
-    }
 
-    true
+            ActionType::Jump => {
+                let p = state.players.get(world, who);
+                if !p.jump() {
+                    return unsupported("player movement", player_block_pos(&**p), _tick);
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::AssertPlayer {
+                pos,
+                tolerance,
+                on_ground,
+                game_mode,
+                health,
+                food,
+                effects,
+            } => {
+                let p = state.players.get(world, who);
+                let actual_pos = p.position();
+                let block_pos = player_block_pos(&**p);
+
+                if let Some(expected) = pos {
+                    let Some(actual_pos) = actual_pos else {
+                        return unsupported("player positioning", block_pos, _tick);
+                    };
+                    if !within_distance(actual_pos, *expected, *tolerance) {
+                        let expected = format!(
+                            "({}, {}, {}) within {}",
+                            expected[0], expected[1], expected[2], tolerance
+                        );
+                        let actual = format!(
+                            "({:.2}, {:.2}, {:.2})",
+                            actual_pos[0], actual_pos[1], actual_pos[2]
+                        );
+                        return player_mismatch("position", expected, actual, block_pos, _tick);
+                    }
+                }
+
+                if let Some(expected) = on_ground {
+                    let Some(actual) = p.on_ground() else {
+                        return unsupported("player ground state", block_pos, _tick);
+                    };
+                    if actual != *expected {
+                        return ActionOutcome::AssertFailed(AssertFailure::new(
+                            _tick,
+                            format!(
+                                "Player on_ground mismatch: expected {}, got {}",
+                                expected, actual
+                            ),
+                            block_pos,
+                            InfoType::String(format!("on_ground={}", expected)),
+                            InfoType::String(format!("on_ground={}", actual)),
+                        ));
+                    }
+                }
+
+                if let Some(expected) = game_mode {
+                    let Some(actual) = p.game_mode() else {
+                        return unsupported("player game modes", block_pos, _tick);
+                    };
+                    if actual != *expected {
+                        return player_mismatch(
+                            "game mode",
+                            expected.to_string(),
+                            actual.to_string(),
+                            block_pos,
+                            _tick,
+                        );
+                    }
+                }
+
+                if let Some(expected) = health {
+                    let Some(actual) = p.health() else {
+                        return unsupported("player health", block_pos, _tick);
+                    };
+                    if !expected.matches(actual as f64) {
+                        return player_mismatch(
+                            "health",
+                            expected.to_string(),
+                            actual.to_string(),
+                            block_pos,
+                            _tick,
+                        );
+                    }
+                }
+
+                if let Some(expected) = food {
+                    let Some(actual) = p.food_level() else {
+                        return unsupported("player hunger", block_pos, _tick);
+                    };
+                    if !expected.matches(actual as f64) {
+                        return player_mismatch(
+                            "food level",
+                            expected.to_string(),
+                            actual.to_string(),
+                            block_pos,
+                            _tick,
+                        );
+                    }
+                }
+
+                if !effects.is_empty() {
+                    let Some(actual) = p.effects() else {
+                        return unsupported("status effects", block_pos, _tick);
+                    };
+                    let missing = effects.iter().find(|expected| {
+                        !actual.iter().any(|effect| {
+                            ids_match(&effect.id, &expected.id)
+                                && effect.amplifier == expected.amplifier
+                        })
+                    });
+                    if let Some(missing) = missing {
+                        let actual = actual
+                            .iter()
+                            .map(format_effect)
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        return player_mismatch(
+                            "effects",
+                            format_effect(missing),
+                            if actual.is_empty() {
+                                "no effects".to_string()
+                            } else {
+                                actual
+                            },
+                            block_pos,
+                            _tick,
+                        );
+                    }
+                }
+                ActionOutcome::AssertPassed
+            }
+
+            ActionType::SetInventory { items } => {
+                let p = state.players.get(world, who);
+                for slot in PlayerSlot::ALL {
+                    p.set_slot(slot, items.get(&slot));
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::ClearInventory => {
+                let p = state.players.get(world, who);
+                for slot in PlayerSlot::ALL {
+                    p.set_slot(slot, None);
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::SpawnEntity { entity, pos, data } => {
+                if world.spawn_entity(entity, *pos, data.as_ref()).is_none() {
+                    let block_pos = pos.map(|c| c.floor() as i32);
+                    return ActionOutcome::AssertFailed(AssertFailure::new(
+                        _tick,
+                        format!("Cannot spawn entity '{}' at {:?}", entity, pos),
+                        block_pos,
+                        InfoType::String(format!("spawned {}", entity)),
+                        InfoType::String("not spawned".to_string()),
+                    ));
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::Command { command } => {
+                let Some(result) = world.execute_command(&relative_command(command, origin)) else {
+                    return unsupported("commands", origin, _tick);
+                };
+                if !result.success {
+                    return ActionOutcome::AssertFailed(AssertFailure::new(
+                        _tick,
+                        format!("Command '{}' failed: {}", command, result.output),
+                        origin,
+                        InfoType::String("success".to_string()),
+                        InfoType::String(result.output),
+                    ));
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::AssertCommand {
+                command,
+                success,
+                output,
+            } => {
+                let Some(result) = world.execute_command(&relative_command(command, origin)) else {
+                    return unsupported("commands", origin, _tick);
+                };
+                // Without any expectation the command must succeed
+                let success = success.or(output.is_none().then_some(true));
+                if let Some(expected) = success
+                    && result.success != expected
+                {
+                    let status = |success: bool| if success { "success" } else { "failure" };
+                    return ActionOutcome::AssertFailed(AssertFailure::new(
+                        _tick,
+                        format!(
+                            "Command '{}' result mismatch: expected {}, got {} ({})",
+                            command,
+                            status(expected),
+                            status(result.success),
+                            result.output
+                        ),
+                        origin,
+                        InfoType::String(status(expected).to_string()),
+                        InfoType::String(status(result.success).to_string()),
+                    ));
+                }
+                if let Some(expected) = output
+                    && !result.output.contains(expected.as_str())
+                {
+                    return ActionOutcome::AssertFailed(AssertFailure::new(
+                        _tick,
+                        format!(
+                            "Command '{}' output mismatch: expected output containing '{}', got '{}'",
+                            command, expected, result.output
+                        ),
+                        origin,
+                        InfoType::String(format!("output containing '{}'", expected)),
+                        InfoType::String(result.output),
+                    ));
+                }
+                ActionOutcome::AssertPassed
+            }
+
+            ActionType::ScheduleTick {
+                pos,
+                delay,
+                priority,
+                target,
+            } => {
+                let tick = ScheduledTick {
+                    target: target.clone().unwrap_or_else(|| world.get_block(*pos).id),
+                    delay: *delay,
+                    priority: *priority,
+                };
+                if !world.schedule_tick(*pos, &tick) {
+                    return unsupported("scheduled ticks", *pos, _tick);
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::AssertScheduled {
+                pos,
+                target,
+                delay,
+                priority,
+                count,
+            } => {
+                let Some(ticks) = world.scheduled_ticks(*pos) else {
+                    return unsupported("scheduled ticks", *pos, _tick);
+                };
+                let matching = ticks
+                    .iter()
+                    .filter(|tick| {
+                        target
+                            .as_deref()
+                            .is_none_or(|target| ids_match(&tick.target, target))
+                            && delay
+                                .as_ref()
+                                .is_none_or(|delay| delay.matches(tick.delay as f64))
+                            && priority.is_none_or(|priority| tick.priority == priority)
+                    })
+                    .count();
+                let passed = match count {
+                    Some(count) => matching == *count,
+                    None => matching > 0,
+                };
+                if !passed {
+                    let mut expected = match count {
+                        Some(count) => format!("{} scheduled tick(s)", count),
+                        None => "a scheduled tick".to_string(),
+                    };
+                    if let Some(target) = target {
+                        expected.push_str(&format!(" for {}", target));
+                    }
+                    if let Some(delay) = delay {
+                        expected.push_str(&format!(" with delay {}", delay));
+                    }
+                    if let Some(priority) = priority {
+                        expected.push_str(&format!(" with priority {}", priority));
+                    }
+                    let pending = ticks
+                        .iter()
+                        .map(|tick| {
+                            format!(
+                                "{} in {} (priority {})",
+                                tick.target, tick.delay, tick.priority
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let actual = format!("{} matching; pending: [{}]", matching, pending);
+                    return ActionOutcome::AssertFailed(AssertFailure::new(
+                        _tick,
+                        format!(
+                            "Scheduled tick mismatch at {:?}: expected {}, got {}",
+                            pos, expected, actual
+                        ),
+                        *pos,
+                        InfoType::String(expected),
+                        InfoType::String(actual),
+                    ));
+                }
+                ActionOutcome::AssertPassed
+            }
+
+            ActionType::AssertEvents {
+                region,
+                since,
+                until,
+                events,
+                ordered,
+                absent,
+            } => {
+                let region = &ordered_region(*region);
+                let Some(captured) = world.captured_events() else {
+                    return unsupported("event capture", region[0], _tick);
+                };
+                // Timeline ticks are relative to the test start, event ticks are world ticks
+                let start = world.current_tick().saturating_sub(_tick as u64);
+                let since = start + since.unwrap_or(0) as u64;
+                let until = until.map(|until| start + until as u64);
+                let captured: Vec<WorldEvent> = captured
+                    .into_iter()
+                    .filter(|e| {
+                        e.tick >= since
+                            && until.is_none_or(|until| e.tick <= until)
+                            && in_region(e.pos, region)
+                    })
+                    .collect();
+
+                if let Some(missing) = missing_event(events, &captured, *ordered) {
+                    let mut expected = format_event_matcher(&events[missing]);
+                    if *ordered && missing > 0 {
+                        expected.push_str(&format!(
+                            " after {}",
+                            format_event_matcher(&events[missing - 1])
+                        ));
+                    }
+                    let actual = format_events(&captured, start);
+                    return ActionOutcome::AssertFailed(AssertFailure::new(
+                        _tick,
+                        format!(
+                            "Event missing in region {:?}: expected {}, got {}",
+                            region, expected, actual
+                        ),
+                        events[missing].pos.unwrap_or(region[0]),
+                        InfoType::String(expected),
+                        InfoType::String(actual),
+                    ));
+                }
+
+                for matcher in absent {
+                    if let Some(event) = captured.iter().find(|e| event_matches(matcher, e)) {
+                        let expected = format!("no {}", format_event_matcher(matcher));
+                        let actual = format_event(event, start);
+                        return ActionOutcome::AssertFailed(AssertFailure::new(
+                            _tick,
+                            format!(
+                                "Unexpected event in region {:?}: expected {}, got {}",
+                                region, expected, actual
+                            ),
+                            event.pos,
+                            InfoType::String(expected),
+                            InfoType::String(actual),
+                        ));
+                    }
+                }
+                ActionOutcome::AssertPassed
+            }
+
+            ActionType::SetBiome { region, biome } => {
+                if !state
+                    .world_settings
+                    .set_biome(world, *region, &namespaced(biome))
+                {
+                    return unsupported("biomes", region[0], _tick);
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::AssertBiome { pos, biome } => {
+                let Some(actual) = world.get_biome(*pos) else {
+                    return unsupported("biomes", *pos, _tick);
+                };
+                if !ids_match(&actual, biome) {
+                    return ActionOutcome::AssertFailed(AssertFailure::new(
+                        _tick,
+                        format!(
+                            "Biome mismatch at {:?}: expected {}, got {}",
+                            pos,
+                            namespaced(biome),
+                            actual
+                        ),
+                        *pos,
+                        InfoType::String(namespaced(biome)),
+                        InfoType::String(actual),
+                    ));
+                }
+                ActionOutcome::AssertPassed
+            }
+
+            ActionType::RandomTick { pos } => {
+                if !world.random_tick(*pos) {
+                    return unsupported("random ticks", *pos, _tick);
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::SetTime { time } => {
+                if !state.world_settings.set_day_time(world, *time) {
+                    return unsupported("world time", origin, _tick);
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::SetWeather { weather } => {
+                if !state.world_settings.set_weather(world, *weather) {
+                    return unsupported("weather", origin, _tick);
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::SetGameRule { rule, value } => {
+                if !state.world_settings.set_game_rule(world, rule, value) {
+                    return ActionOutcome::AssertFailed(AssertFailure::new(
+                        _tick,
+                        game_rule_error(rule, value),
+                        origin,
+                        InfoType::String(format!("{} = {}", rule, value)),
+                        InfoType::String("rule not set".to_string()),
+                    ));
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::KillEntities { region, entity } => {
+                let region = ordered_region(*region);
+                let Some(entities) = world.entities_in(region) else {
+                    return unsupported("entities", region[0], _tick);
+                };
+                for e in entities {
+                    if entity_matches(&*e, entity.as_deref(), None, 0.0, None) {
+                        world.remove_entity(e.id());
+                    }
+                }
+                ActionOutcome::Action
+            }
+
+            ActionType::AssertDrops {
+                region,
+                items,
+                since,
+            } => {
+                let region = ordered_region(*region);
+                let Some(entities) = world.entities_in(region) else {
+                    return unsupported("entities", region[0], _tick);
+                };
+                // Timeline ticks are relative to the test start, spawn ticks are world ticks
+                let since = since.map(|since| {
+                    (world.current_tick() + since as u64).saturating_sub(_tick as u64)
+                });
+
+                let mut actual: BTreeMap<String, u32> = BTreeMap::new();
+                for e in &entities {
+                    let Some(item) = e.item() else {
+                        continue;
+                    };
+                    if let Some(since) = since {
+                        match e.spawn_tick() {
+                            Some(spawned) if spawned < since => continue,
+                            Some(_) => {}
+                            None => return unsupported("entity spawn ticks", region[0], _tick),
+                        }
+                    }
+                    *actual.entry(namespaced(&item.id)).or_default() += item.count as u32;
+                }
+
+                let mut expected: BTreeMap<String, u32> = BTreeMap::new();
+                for item in items {
+                    *expected.entry(namespaced(&item.id)).or_default() += item.count as u32;
+                }
+
+                if actual != expected {
+                    let diff = drops_diff(&expected, &actual);
+                    let expected = format_drops(&expected);
+                    let actual = format!("{} ({})", format_drops(&actual), diff);
+                    return ActionOutcome::AssertFailed(AssertFailure::new(
+                        _tick,
+                        format!(
+                            "Drops mismatch in region {:?}: expected {}, got {}",
+                            region, expected, actual
+                        ),
+                        region[0],
+                        InfoType::String(expected),
+                        InfoType::String(actual),
+                    ));
+                }
+                ActionOutcome::AssertPassed
+            }
+
+            ActionType::AssertEntities {
+                region,
+                entity,
+                pos,
+                tolerance,
+                data,
+                count,
+            } => {
+                let region = ordered_region(*region);
+                let Some(entities) = world.entities_in(region) else {
+                    return unsupported("entities", region[0], _tick);
+                };
+                let matching = entities
+                    .iter()
+                    .filter(|e| {
+                        entity_matches(&***e, entity.as_deref(), *pos, *tolerance, data.as_ref())
+                    })
+                    .count();
+                let passed = match count {
+                    Some(count) => matching == *count,
+                    None => matching > 0,
+                };
+                if !passed {
+                    let mut expected = match count {
+                        Some(count) => {
+                            format!("{} x {}", count, entity.as_deref().unwrap_or("entity"))
+                        }
+                        None => format!("at least one {}", entity.as_deref().unwrap_or("entity")),
+                    };
+                    if let Some(pos) = pos {
+                        expected.push_str(&format!(
+                            " within {} of ({}, {}, {})",
+                            tolerance, pos[0], pos[1], pos[2]
+                        ));
+                    }
+                    if let Some(data) = data {
+                        expected.push_str(&format!(" with {}", data));
+                    }
+                    let found = entities
+                        .iter()
+                        .map(|e| describe_entity(&**e))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let actual = format!("{} matching; in region: [{}]", matching, found);
+                    return ActionOutcome::AssertFailed(AssertFailure::new(
+                        _tick,
+                        format!(
+                            "Entity mismatch in region {:?}: expected {}, got {}",
+                            region, expected, actual
+                        ),
+                        region[0],
+                        InfoType::String(expected),
+                        InfoType::String(actual),
+                    ));
+                }
+                ActionOutcome::AssertPassed
+            }
+
+            ActionType::AssertSlot {
+                slot,
+                item,
+                count,
+                components,
+            } => {
+                let p = state.players.get(world, who);
+                let expected = match item {
+                    Some(item_id) => {
+                        let mut item = Item::with_count(item_id, *count);
+                        item.components = components.clone();
+                        item
+                    }
+                    None => Item::empty(),
+                };
+                let actual = p.get_slot(*slot);
+                if !item_matches(&expected, actual.as_ref()) {
+                    let actual = actual.unwrap_or_else(Item::empty);
+                    return ActionOutcome::AssertFailed(AssertFailure::new(
+                        _tick,
+                        format!(
+                            "Player slot {:?} mismatch: expected '{}', got '{}'",
+                            slot,
+                            expected.to_command(),
+                            actual.to_command(),
+                        ),
+                        [0, 0, 0],
+                        InfoType::Item(expected),
+                        InfoType::Item(actual),
+                    ));
+                }
+                ActionOutcome::AssertPassed
+            }
+
+            ActionType::AssertSelectedHotbar { slot } => {
+                let p = state.players.get(world, who);
+                let actual = p.selected_hotbar();
+                if actual != *slot {
+                    return ActionOutcome::AssertFailed(AssertFailure::new(
+                        _tick,
+                        format!(
+                            "Selected hotbar mismatch: expected {}, got {}",
+                            slot, actual
+                        ),
+                        [0, 0, 0],
+                        InfoType::String(format!("hotbar {}", slot)),
+                        InfoType::String(format!("hotbar {}", actual)),
+                    ));
+                }
+                ActionOutcome::AssertPassed
+            }
+        }
+    }
+
+    /// Check if actual block matches expected, resolving block tags through the adapter.
+    ///
+    /// Returns the tag name as error if the adapter cannot resolve it or the tag is empty.
+    fn block_matches(&self, actual: &Block, expected: &Block) -> Result<bool, String> {
+        let tag_members = match expected.tag() {
+            Some(tag) => match self.adapter.block_tag(&tag) {
+                Some(ids) if !ids.is_empty() => Some(ids),
+                _ => return Err(tag),
+            },
+            None => None,
+        };
+        Ok(block_matches(actual, expected, tag_members.as_deref()))
+    }
+}
+
+/// Simple mode: put a fresh item in the given hand (hotbar 1 for the main hand).
+fn hold_item(player: &mut dyn FlintPlayer, item_id: &str, hand: Hand) {
+    let item = Item::new(item_id);
+    match hand {
+        Hand::Main => {
+            player.set_slot(PlayerSlot::Hotbar1, Some(&item));
+            player.select_hotbar(1);
+        }
+        Hand::Off => player.set_slot(PlayerSlot::OffHand, Some(&item)),
+    }
+}
+
+/// Block position of a player for failure reports, the origin if the adapter cannot tell.
+fn player_block_pos(player: &dyn FlintPlayer) -> BlockPos {
+    let pos = player.position();
+    pos.map_or([0, 0, 0], |pos| pos.map(|c| c.floor() as i32))
+}
+
+/// Find the entity closest to `pos` within `tolerance` blocks, optionally of a given type.
+///
+/// Returns a failed outcome if entities are unsupported or no entity matches.
+fn find_entity(
+    world: &dyn FlintWorld,
+    entity_type: Option<&str>,
+    pos: EntityPos,
+    tolerance: f64,
+    tick: u32,
+) -> Result<EntityId, Box<ActionOutcome>> {
+    let block_pos = pos.map(|c| c.floor() as i32);
+    let region = [
+        pos.map(|c| (c - tolerance).floor() as i32),
+        pos.map(|c| (c + tolerance).floor() as i32),
+    ];
+    let Some(entities) = world.entities_in(region) else {
+        return Err(Box::new(unsupported("entities", block_pos, tick)));
+    };
+    let distance_sq = |e: &dyn FlintEntity| -> f64 {
+        let actual = e.position();
+        (0..3).map(|i| (actual[i] - pos[i]).powi(2)).sum()
+    };
+    entities
+        .iter()
+        .filter(|e| entity_matches(&***e, entity_type, Some(pos), tolerance, None))
+        .min_by(|a, b| distance_sq(&***a).total_cmp(&distance_sq(&***b)))
+        .map(|e| e.id())
+        .ok_or_else(|| {
+            let expected = format!(
+                "{} within {} of ({}, {}, {})",
+                entity_type.unwrap_or("an entity"),
+                tolerance,
+                pos[0],
+                pos[1],
+                pos[2]
+            );
+            Box::new(ActionOutcome::AssertFailed(AssertFailure::new(
+                tick,
+                format!("No entity found: expected {}", expected),
+                block_pos,
+                InfoType::String(expected),
+                InfoType::String("no matching entity".to_string()),
+            )))
+        })
+}
+
+/// Failure for an assertion referencing a block tag the adapter cannot resolve.
+fn unknown_tag(tag: String, pos: [i32; 3], tick: u32) -> ActionOutcome {
+    ActionOutcome::AssertFailed(AssertFailure::new(
+        tick,
+        format!("Cannot check unknown or empty block tag '#{}'", tag),
+        pos,
+        InfoType::String(format!("a block from #{}", tag)),
+        InfoType::String("unknown tag".to_string()),
+    ))
+}
+
+/// Failure for a placement given a block tag instead of a concrete block.
+fn placed_tag(tag: String, pos: [i32; 3], tick: u32) -> ActionOutcome {
+    ActionOutcome::AssertFailed(AssertFailure::new(
+        tick,
+        format!("Cannot place block tag '#{}', use a block id", tag),
+        pos,
+        InfoType::String("a block id".to_string()),
+        InfoType::String(format!("#{}", tag)),
+    ))
+}
+
+/// Failure for a container action targeting a block that is not a container.
+fn no_container(world: &dyn FlintWorld, pos: [i32; 3], tick: u32) -> ActionOutcome {
+    ActionOutcome::AssertFailed(AssertFailure::new(
+        tick,
+        format!("No container at {:?}", pos),
+        pos,
+        InfoType::String("a container".to_string()),
+        InfoType::Block(world.get_block(pos)),
+    ))
+}
+
+/// Failure for a container action referencing a slot beyond the container size.
+fn slot_out_of_range(
+    verb: &str,
+    pos: [i32; 3],
+    size: usize,
+    slot: usize,
+    tick: u32,
+) -> ActionOutcome {
+    ActionOutcome::AssertFailed(AssertFailure::new(
+        tick,
+        format!(
+            "Container at {:?} has {} slots, cannot {} slot {}",
+            pos, size, verb, slot
+        ),
+        pos,
+        InfoType::String(format!("slot {}", slot)),
+        InfoType::String(format!("{} slots", size)),
+    ))
+}
+
+/// Failure for a container slot the adapter refused to set.
+fn slot_not_set(pos: [i32; 3], slot: usize, item: &Item, tick: u32) -> ActionOutcome {
+    ActionOutcome::AssertFailed(AssertFailure::new(
+        tick,
+        format!(
+            "Failed to set container slot {} at {:?} to '{}'",
+            slot,
+            pos,
+            item.to_command()
+        ),
+        pos,
+        InfoType::Item(item.clone()),
+        InfoType::String(format!("slot {} unchanged", slot)),
+    ))
+}
+
+/// Reason a game rule could not be set, the adapter does not tell the causes apart.
+fn game_rule_error(rule: &str, value: &GameRuleValue) -> String {
+    format!(
+        "Cannot set game rule '{}' to {}: the rule is unknown, the value has the wrong type \
+         or the adapter does not support game rules",
+        rule, value
+    )
+}
+
+/// Failure for an action that needs an optional adapter capability it does not provide.
+fn unsupported(feature: &str, pos: [i32; 3], tick: u32) -> ActionOutcome {
+    ActionOutcome::AssertFailed(AssertFailure::new(
+        tick,
+        format!("Adapter does not support {}", feature),
+        pos,
+        InfoType::String(format!("{} support", feature)),
+        InfoType::String("unsupported".to_string()),
+    ))
+}
+
+/// Run a command at the test origin so `~` coordinates are relative to the test.
+fn relative_command(command: &str, origin: BlockPos) -> String {
+    format!(
+        "execute positioned {} {} {} run {}",
+        origin[0],
+        origin[1],
+        origin[2],
+        command.trim_start_matches('/').trim()
+    )
+}
+
+/// Order the corners of a region as `[min, max]`.
+fn ordered_region(region: [BlockPos; 2]) -> [BlockPos; 2] {
+    [
+        std::array::from_fn(|i| region[0][i].min(region[1][i])),
+        std::array::from_fn(|i| region[0][i].max(region[1][i])),
+    ]
+}
+
+/// Check if a position lies in a `[min, max]` region (both corners inclusive).
+fn in_region(pos: BlockPos, region: &[BlockPos; 2]) -> bool {
+    (0..3).all(|i| pos[i] >= region[0][i] && pos[i] <= region[1][i])
+}
+
+/// Check if a captured event matches the kind, position and id of a matcher.
+fn event_matches(matcher: &EventMatcher, event: &WorldEvent) -> bool {
+    matcher.kind == event.kind
+        && matcher.pos.is_none_or(|pos| pos == event.pos)
+        && matcher
+            .id
+            .as_deref()
+            .is_none_or(|id| ids_match(&event.id, id))
+}
+
+/// Index of the first expected event without a matching captured event.
+///
+/// With `ordered`, matches must appear in the listed order, other events may occur in between.
+fn missing_event(expected: &[EventMatcher], events: &[WorldEvent], ordered: bool) -> Option<usize> {
+    if ordered {
+        let mut remaining = events.iter();
+        expected
+            .iter()
+            .position(|matcher| !remaining.any(|e| event_matches(matcher, e)))
+    } else {
+        expected
+            .iter()
+            .position(|matcher| !events.iter().any(|e| event_matches(matcher, e)))
+    }
+}
+
+/// Format an event matcher like `block_update minecraft:repeater at [0, 1, 0]`.
+fn format_event_matcher(matcher: &EventMatcher) -> String {
+    let mut text = matcher.kind.to_string();
+    if let Some(id) = &matcher.id {
+        text.push_str(&format!(" {}", namespaced(id)));
+    }
+    if let Some(pos) = matcher.pos {
+        text.push_str(&format!(" at {:?}", pos));
+    }
+    text
+}
+
+/// Format an event with its tick relative to the test start.
+fn format_event(event: &WorldEvent, start: u64) -> String {
+    format!(
+        "{} {} at {:?} (tick {})",
+        event.kind,
+        event.id,
+        event.pos,
+        event.tick.saturating_sub(start)
+    )
+}
+
+/// Format captured events, listing at most the first 20.
+fn format_events(events: &[WorldEvent], start: u64) -> String {
+    const MAX_LISTED: usize = 20;
+    if events.is_empty() {
+        return "no events".to_string();
+    }
+    let mut text = events
+        .iter()
+        .take(MAX_LISTED)
+        .map(|e| format_event(e, start))
+        .collect::<Vec<_>>()
+        .join(", ");
+    if events.len() > MAX_LISTED {
+        text.push_str(&format!(", ... {} more", events.len() - MAX_LISTED));
+    }
+    text
+}
+
+/// Failure for a player state assertion.
+fn player_mismatch(
+    what: &str,
+    expected: String,
+    actual: String,
+    pos: [i32; 3],
+    tick: u32,
+) -> ActionOutcome {
+    ActionOutcome::AssertFailed(AssertFailure::new(
+        tick,
+        format!(
+            "Player {} mismatch: expected {}, got {}",
+            what, expected, actual
+        ),
+        pos,
+        InfoType::String(expected),
+        InfoType::String(actual),
+    ))
+}
+
+/// Format a status effect like `minecraft:haste 2` (level, not amplifier).
+fn format_effect(effect: &StatusEffect) -> String {
+    format!("{} {}", namespaced(&effect.id), effect.amplifier as u32 + 1)
+}
+
+/// Apply the initial state of a player from the test setup.
+///
+/// Returns the name of the first feature the adapter does not support.
+fn apply_player_config(
+    player: &mut dyn FlintPlayer,
+    config: &PlayerConfig,
+) -> Result<(), &'static str> {
+    // Set initial inventory
+    for (slot_name, item) in &config.inventory {
+        player.set_slot(*slot_name, Some(item));
+    }
+
+    // Set initial hotbar selection
+    player.select_hotbar(config.selected_hotbar);
+
+    // Set initial position
+    if let Some(pos) = config.position
+        && !player.teleport(pos, config.yaw, config.pitch)
+    {
+        return Err("player positioning");
+    }
+
+    if let Some(mode) = config.game_mode
+        && !player.set_game_mode(mode)
+    {
+        return Err("player game modes");
+    }
+    if let Some(health) = config.health
+        && !player.set_health(health)
+    {
+        return Err("player health");
+    }
+    if let Some(food) = config.food
+        && !player.set_food_level(food)
+    {
+        return Err("player hunger");
+    }
+    for effect in &config.effects {
+        if !player.add_effect(effect) {
+            return Err("status effects");
+        }
+    }
+    Ok(())
+}
+
+/// Check if an entity matches the type, position and data filters of an entity action.
+fn entity_matches(
+    entity: &dyn FlintEntity,
+    entity_type: Option<&str>,
+    pos: Option<EntityPos>,
+    tolerance: f64,
+    data: Option<&Nbt>,
+) -> bool {
+    if entity_type.is_some_and(|expected| !ids_match(&entity.entity_type(), expected)) {
+        return false;
+    }
+    if pos.is_some_and(|expected| !within_distance(entity.position(), expected, tolerance)) {
+        return false;
+    }
+    if let Some(expected) = data
+        && !entity
+            .data()
+            .is_some_and(|actual| expected.matches(&actual))
+    {
+        return false;
+    }
+    true
+}
+
+/// Check if two positions are at most `tolerance` blocks apart.
+fn within_distance(a: EntityPos, b: EntityPos, tolerance: f64) -> bool {
+    let distance_sq: f64 = (0..3).map(|i| (a[i] - b[i]).powi(2)).sum();
+    distance_sq <= tolerance * tolerance
+}
+
+/// Short description of an entity for failure messages, e.g. `minecraft:item @ (1.50, 1.00, 0.50)`.
+fn describe_entity(entity: &dyn FlintEntity) -> String {
+    let pos = entity.position();
+    format!(
+        "{} @ ({:.2}, {:.2}, {:.2})",
+        entity.entity_type(),
+        pos[0],
+        pos[1],
+        pos[2]
+    )
+}
+
+/// Add the default `minecraft:` namespace to a resource id if it has none.
+fn namespaced(id: &str) -> String {
+    if id.contains(':') {
+        id.to_string()
+    } else {
+        format!("minecraft:{}", id)
+    }
+}
+
+/// Format aggregated drops like `minecraft:cobblestone x2, minecraft:flint x1`.
+fn format_drops(drops: &BTreeMap<String, u32>) -> String {
+    if drops.is_empty() {
+        return "no drops".to_string();
+    }
+    drops
+        .iter()
+        .map(|(id, count)| format!("{} x{}", id, count))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Describe the difference between expected and actual drops, e.g. `missing minecraft:flint x1`.
+fn drops_diff(expected: &BTreeMap<String, u32>, actual: &BTreeMap<String, u32>) -> String {
+    let mut missing = BTreeMap::new();
+    let mut unexpected = BTreeMap::new();
+    for id in expected.keys().chain(actual.keys()) {
+        let expected = expected.get(id).copied().unwrap_or(0);
+        let actual = actual.get(id).copied().unwrap_or(0);
+        if expected > actual {
+            missing.insert(id.clone(), expected - actual);
+        } else if actual > expected {
+            unexpected.insert(id.clone(), actual - expected);
+        }
+    }
+
+    let mut parts = Vec::new();
+    if !missing.is_empty() {
+        parts.push(format!("missing {}", format_drops(&missing)));
+    }
+    if !unexpected.is_empty() {
+        parts.push(format!("unexpected {}", format_drops(&unexpected)));
+    }
+    parts.join("; ")
+}
+
+/// Compare two resource ids, treating a missing namespace as `minecraft:`.
+fn ids_match(actual: &str, expected: &str) -> bool {
+    let actual = actual.strip_prefix("minecraft:").unwrap_or(actual);
+    let expected = expected.strip_prefix("minecraft:").unwrap_or(expected);
+    actual == expected
+}
+
+/// Check if actual block matches expected.
+///
+/// `tag_members` holds the resolved block ids when `expected` is a tag reference.
+fn block_matches(actual: &Block, expected: &Block, tag_members: Option<&[String]>) -> bool {
+    // Check block ID (or tag membership)
+    let id_matches = if expected.is_tag() {
+        tag_members.is_some_and(|ids| ids.iter().any(|id| ids_match(&actual.id, id)))
+    } else {
+        ids_match(&actual.id, &expected.id)
+    };
+    if !id_matches {
+        return false;
+    }
+
+    // Check properties if specified in expected
+    for (key, expected_value) in &expected.properties {
+        if let Some(actual_value) = actual.properties.get(key) {
+            if actual_value != expected_value {
+                return false;
+            }
+        } else {
+            // Property expected but not found in actual block - this is a mismatch
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Check if an item in a slot matches the expected item (id, count and components).
+///
+/// An empty expected item matches an empty slot. Components are partially matched,
+/// see [`components_match`].
+fn item_matches(expected: &Item, actual: Option<&Item>) -> bool {
+    match actual.filter(|item| !item.is_empty()) {
+        None => expected.is_empty(),
+        Some(actual) => {
+            ids_match(&actual.id, &expected.id)
+                && actual.count == expected.count
+                && components_match(expected, actual)
+        }
+    }
+}
+
+/// Check that every expected component is present on the actual item and matches it,
+/// other components are ignored.
+///
+/// Enchantment ids default to the `minecraft:` namespace, like item ids.
+fn components_match(expected: &Item, actual: &Item) -> bool {
+    expected.components.iter().all(|(key, value)| {
+        actual
+            .component(key)
+            .is_some_and(|actual| match namespaced(key).as_str() {
+                "minecraft:enchantments" | "minecraft:stored_enchantments" => {
+                    namespaced_keys(value).matches(&namespaced_keys(actual))
+                }
+                _ => value.matches(actual),
+            })
+    })
+}
+
+/// Namespace every compound key, so `{"silk_touch": 1}` equals `{"minecraft:silk_touch": 1}`.
+fn namespaced_keys(nbt: &Nbt) -> Nbt {
+    match nbt {
+        Nbt::Compound(map) => Nbt::Compound(
+            map.iter()
+                .map(|(key, value)| (namespaced(key), namespaced_keys(value)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Check if the fluid at a position matches the expected fluid id and state.
+///
+/// `minecraft:empty` only matches no fluid, the state is not checked then.
+/// Fluid in waterlogged blocks matches like a fluid block.
+fn fluid_matches(
+    actual: &FluidState,
+    fluid: &str,
+    amount: Option<&NumberMatcher>,
+    falling: Option<bool>,
+    source: Option<bool>,
+) -> bool {
+    let expected_empty = ids_match(fluid, "minecraft:empty");
+    if expected_empty || actual.is_empty() {
+        return expected_empty == actual.is_empty();
+    }
+    ids_match(&actual.id, fluid)
+        && amount.is_none_or(|amount| amount.matches(actual.amount as f64))
+        && falling.is_none_or(|falling| falling == actual.falling)
+        && source.is_none_or(|source| source == actual.source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_spec::{BlockFace, EventKind, GameMode, WorldConfig, WorldOption};
+    use crate::test_support::{
+        StubAdapter, StubPlayer, StubWorld, delegate_player, delegate_world, failure,
+        failure_message, run, spec,
+    };
+    use std::collections::HashMap;
+    use std::sync::{Mutex, MutexGuard};
+
+    #[test]
+    fn test_block_matches_without_namespace() {
+        let actual = Block::new("minecraft:stone");
+        assert!(block_matches(&actual, &Block::new("stone"), None));
+        assert!(block_matches(&actual, &Block::new("minecraft:stone"), None));
+        assert!(!block_matches(&actual, &Block::new("dirt"), None));
+    }
+
+    #[test]
+    fn test_relative_command() {
+        assert_eq!(
+            relative_command("/setblock ~1 ~ ~ minecraft:stone", [16, 0, -16]),
+            "execute positioned 16 0 -16 run setblock ~1 ~ ~ minecraft:stone"
+        );
+    }
+
+    #[test]
+    fn test_item_matches() {
+        let stone = Item::with_count("minecraft:stone", 3);
+        assert!(item_matches(&stone, Some(&Item::with_count("stone", 3))));
+        assert!(!item_matches(&stone, Some(&Item::with_count("stone", 2))));
+        assert!(!item_matches(&stone, None));
+
+        let air = Item::new("minecraft:air");
+        assert!(item_matches(&air, None));
+        assert!(item_matches(&air, Some(&Item::empty())));
+        assert!(!item_matches(&air, Some(&stone)));
+    }
+
+    #[test]
+    fn test_item_matches_components() {
+        let enchantments: Nbt = serde_json::from_str(r#"{"minecraft:silk_touch": 1}"#).unwrap();
+        let actual = Item::new("minecraft:diamond_pickaxe")
+            .with_component("minecraft:enchantments", enchantments)
+            .with_component("minecraft:damage", Nbt::Int(3));
+
+        let expected = Item::new("minecraft:diamond_pickaxe").with_component(
+            "enchantments",
+            serde_json::from_str(r#"{"silk_touch": 1}"#).unwrap(),
+        );
+        assert!(item_matches(&expected, Some(&actual)));
+
+        let expected = Item::new("minecraft:diamond_pickaxe").with_component(
+            "enchantments",
+            serde_json::from_str(r#"{"minecraft:silk_touch": 1}"#).unwrap(),
+        );
+        assert!(item_matches(&expected, Some(&actual)));
+
+        let expected = Item::new("minecraft:diamond_pickaxe").with_component(
+            "enchantments",
+            serde_json::from_str(r#"{"fortune": 1}"#).unwrap(),
+        );
+        assert!(!item_matches(&expected, Some(&actual)));
+
+        let expected = Item::new("minecraft:diamond_pickaxe").with_component("damage", Nbt::Int(0));
+        assert!(!item_matches(&expected, Some(&actual)));
+    }
+
+    #[test]
+    fn test_fluid_matches() {
+        let empty = FluidState::empty();
+        // A waterlogged block reports its water like a water source block
+        let waterlogged = FluidState::source("minecraft:water");
+        assert!(fluid_matches(&empty, "minecraft:empty", None, None, None));
+        assert!(fluid_matches(&empty, "empty", None, None, None));
+        assert!(!fluid_matches(&empty, "minecraft:water", None, None, None));
+        assert!(!fluid_matches(
+            &waterlogged,
+            "minecraft:empty",
+            None,
+            None,
+            None
+        ));
+        assert!(fluid_matches(&waterlogged, "water", None, None, Some(true)));
+        assert!(!fluid_matches(
+            &waterlogged,
+            "minecraft:lava",
+            None,
+            None,
+            None
+        ));
+
+        let flowing = FluidState {
+            id: "minecraft:water".to_string(),
+            amount: 3,
+            falling: false,
+            source: false,
+        };
+        let range = NumberMatcher::Range {
+            min: Some(1.0),
+            max: Some(4.0),
+        };
+        assert!(fluid_matches(
+            &flowing,
+            "water",
+            Some(&range),
+            Some(false),
+            Some(false)
+        ));
+        assert!(!fluid_matches(
+            &flowing,
+            "water",
+            Some(&NumberMatcher::Exact(8.0)),
+            None,
+            None
+        ));
+        assert!(!fluid_matches(&flowing, "water", None, Some(true), None));
+        assert!(!fluid_matches(&flowing, "water", None, None, Some(true)));
+    }
+
+    struct TestEntity {
+        entity_type: &'static str,
+        pos: EntityPos,
+    }
+
+    impl FlintEntity for TestEntity {
+        fn id(&self) -> EntityId {
+            0
+        }
+
+        fn entity_type(&self) -> String {
+            self.entity_type.to_string()
+        }
+
+        fn position(&self) -> EntityPos {
+            self.pos
+        }
+    }
+
+    #[test]
+    fn test_entity_matches() {
+        let cart = TestEntity {
+            entity_type: "minecraft:minecart",
+            pos: [3.4, 1.0, 0.5],
+        };
+        assert!(entity_matches(&cart, None, None, 0.0, None));
+        assert!(entity_matches(&cart, Some("minecart"), None, 0.0, None));
+        assert!(!entity_matches(
+            &cart,
+            Some("minecraft:item"),
+            None,
+            0.0,
+            None
+        ));
+
+        assert!(entity_matches(
+            &cart,
+            None,
+            Some([3.5, 1.0, 0.5]),
+            0.5,
+            None
+        ));
+        assert!(!entity_matches(
+            &cart,
+            None,
+            Some([4.5, 1.0, 0.5]),
+            0.5,
+            None
+        ));
+
+        // Entities without exposed data never match a data filter
+        assert!(!entity_matches(
+            &cart,
+            None,
+            None,
+            0.0,
+            Some(&Nbt::compound())
+        ));
+    }
+
+    /// World with a three slot container at the origin
+    #[derive(Clone, Default)]
+    struct ContainerWorld {
+        base: StubWorld,
+        slots: [Option<Item>; 3],
+        /// Reject every slot change
+        read_only: bool,
+    }
+
+    impl FlintWorld for ContainerWorld {
+        delegate_world!();
+
+        fn container_size(&self, pos: BlockPos) -> Option<usize> {
+            (pos == [0, 0, 0]).then_some(self.slots.len())
+        }
+
+        fn get_container_slot(&self, _pos: BlockPos, slot: usize) -> Option<Item> {
+            self.slots.get(slot).cloned().flatten()
+        }
+
+        fn set_container_slot(&mut self, _pos: BlockPos, slot: usize, item: Option<&Item>) -> bool {
+            if self.read_only || slot >= self.slots.len() {
+                return false;
+            }
+            self.slots[slot] = item.cloned();
+            true
+        }
+    }
+
+    /// Time, weather, known game rules and biomes of a `SettingsWorld`
+    #[derive(Default)]
+    struct Settings {
+        day_time: u64,
+        weather: Weather,
+        /// Setting a rule missing here fails like an unknown rule
+        game_rules: BTreeMap<String, GameRuleValue>,
+        biomes: BTreeMap<BlockPos, String>,
+    }
+
+    /// World with time, weather, game rules and biomes
+    ///
+    /// Copies share their settings, like a world reused by several tests.
+    #[derive(Clone, Default)]
+    struct SettingsWorld {
+        base: StubWorld,
+        settings: Arc<Mutex<Settings>>,
+    }
+
+    impl SettingsWorld {
+        fn new(settings: Settings) -> Self {
+            Self {
+                base: StubWorld::default(),
+                settings: Arc::new(Mutex::new(settings)),
+            }
+        }
+
+        fn settings(&self) -> MutexGuard<'_, Settings> {
+            self.settings.lock().unwrap()
+        }
+    }
+
+    impl FlintWorld for SettingsWorld {
+        delegate_world!();
+
+        fn set_day_time(&mut self, time: u64) -> bool {
+            self.settings().day_time = time;
+            true
+        }
+
+        fn day_time(&self) -> Option<u64> {
+            Some(self.settings().day_time)
+        }
+
+        fn set_weather(&mut self, weather: Weather) -> bool {
+            self.settings().weather = weather;
+            true
+        }
+
+        fn weather(&self) -> Option<Weather> {
+            Some(self.settings().weather)
+        }
+
+        fn set_game_rule(&mut self, rule: &str, value: &GameRuleValue) -> bool {
+            match self.settings().game_rules.get_mut(rule) {
+                Some(current) => *current = *value,
+                None => return false,
+            }
+            true
+        }
+
+        fn game_rule(&self, rule: &str) -> Option<GameRuleValue> {
+            self.settings().game_rules.get(rule).copied()
+        }
+
+        fn set_biome(&mut self, region: [BlockPos; 2], biome: &str) -> bool {
+            let mut settings = self.settings();
+            for x in region[0][0]..=region[1][0] {
+                for y in region[0][1]..=region[1][1] {
+                    for z in region[0][2]..=region[1][2] {
+                        settings.biomes.insert([x, y, z], biome.to_string());
+                    }
+                }
+            }
+            true
+        }
+
+        fn get_biome(&self, pos: BlockPos) -> Option<String> {
+            let biome = self.settings().biomes.get(&pos).cloned();
+            Some(biome.unwrap_or_else(|| "minecraft:plains".to_string()))
+        }
+    }
+
+    #[derive(Clone)]
+    struct StubEntity {
+        id: EntityId,
+        entity_type: String,
+        pos: EntityPos,
+        item: Option<Item>,
+    }
+
+    impl StubEntity {
+        fn new(id: EntityId, entity_type: &str, pos: EntityPos) -> Self {
+            Self {
+                id,
+                entity_type: entity_type.to_string(),
+                pos,
+                item: None,
+            }
+        }
+
+        fn item(id: EntityId, item: Item, pos: EntityPos) -> Self {
+            Self {
+                item: Some(item),
+                ..Self::new(id, "minecraft:item", pos)
+            }
+        }
+    }
+
+    impl FlintEntity for StubEntity {
+        fn id(&self) -> EntityId {
+            self.id
+        }
+
+        fn entity_type(&self) -> String {
+            self.entity_type.clone()
+        }
+
+        fn position(&self) -> EntityPos {
+            self.pos
+        }
+
+        fn item(&self) -> Option<Item> {
+            self.item.clone()
+        }
+    }
+
+    /// World listing and removing a fixed set of entities
+    #[derive(Clone, Default)]
+    struct EntityWorld {
+        base: StubWorld,
+        entities: Vec<StubEntity>,
+    }
+
+    impl FlintWorld for EntityWorld {
+        delegate_world!();
+
+        fn entities_in(&self, region: [BlockPos; 2]) -> Option<Vec<Box<dyn FlintEntity>>> {
+            // Relies on the runner passing `[min, max]`
+            let entities = self
+                .entities
+                .iter()
+                .filter(|e| in_region(e.pos.map(|c| c.floor() as i32), &region))
+                .map(|e| Box::new(e.clone()) as Box<dyn FlintEntity>)
+                .collect();
+            Some(entities)
+        }
+
+        fn remove_entity(&mut self, id: EntityId) -> bool {
+            let count = self.entities.len();
+            self.entities.retain(|e| e.id != id);
+            self.entities.len() < count
+        }
+    }
+
+    /// Player that can be teleported, starting at the origin
+    #[derive(Default)]
+    struct MobilePlayer {
+        base: StubPlayer,
+        pos: EntityPos,
+    }
+
+    impl FlintPlayer for MobilePlayer {
+        delegate_player!();
+
+        fn teleport(&mut self, pos: EntityPos, _yaw: f32, _pitch: f32) -> bool {
+            self.pos = pos;
+            true
+        }
+
+        fn position(&self) -> Option<EntityPos> {
+            Some(self.pos)
+        }
+    }
+
+    /// World keeping scheduled ticks in memory, random ticks age copper blocks
+    #[derive(Clone, Default)]
+    struct TickWorld {
+        base: StubWorld,
+        scheduled: BTreeMap<BlockPos, Vec<ScheduledTick>>,
+    }
+
+    impl FlintWorld for TickWorld {
+        delegate_world!();
+
+        fn scheduled_ticks(&self, pos: BlockPos) -> Option<Vec<ScheduledTick>> {
+            Some(self.scheduled.get(&pos).cloned().unwrap_or_default())
+        }
+
+        fn schedule_tick(&mut self, pos: BlockPos, tick: &ScheduledTick) -> bool {
+            self.scheduled.entry(pos).or_default().push(tick.clone());
+            true
+        }
+
+        fn random_tick(&mut self, pos: BlockPos) -> bool {
+            if self.get_block(pos).id == "minecraft:copper_block" {
+                self.set_block(pos, &Block::new("minecraft:exposed_copper"));
+            }
+            true
+        }
+    }
+
+    /// World with the same light levels everywhere
+    #[derive(Clone, Default)]
+    struct LightWorld {
+        base: StubWorld,
+        block_light: u8,
+        sky_light: u8,
+    }
+
+    impl FlintWorld for LightWorld {
+        delegate_world!();
+
+        fn block_light(&self, _pos: BlockPos) -> Option<u8> {
+            Some(self.block_light)
+        }
+
+        fn sky_light(&self, _pos: BlockPos) -> Option<u8> {
+            Some(self.sky_light)
+        }
+    }
+
+    /// World replaying a fixed list of captured events
+    #[derive(Clone, Default)]
+    struct EventWorld {
+        base: StubWorld,
+        events: Vec<WorldEvent>,
+        /// Region passed to `capture_events`
+        captured_region: Arc<Mutex<Option<[BlockPos; 2]>>>,
+    }
+
+    impl FlintWorld for EventWorld {
+        delegate_world!();
+
+        fn capture_events(&mut self, region: [BlockPos; 2]) -> bool {
+            *self.captured_region.lock().unwrap() = Some(region);
+            true
+        }
+
+        fn captured_events(&self) -> Option<Vec<WorldEvent>> {
+            Some(self.events.clone())
+        }
+    }
+
+    /// Player with a game mode, starting in survival
+    #[derive(Default)]
+    struct GameModePlayer {
+        base: StubPlayer,
+        game_mode: GameMode,
+    }
+
+    impl FlintPlayer for GameModePlayer {
+        delegate_player!();
+
+        fn set_game_mode(&mut self, mode: GameMode) -> bool {
+            self.game_mode = mode;
+            true
+        }
+
+        fn game_mode(&self) -> Option<GameMode> {
+            Some(self.game_mode)
+        }
+    }
+
+    /// Player recording the entities it attacks
+    #[derive(Default)]
+    struct AttackingPlayer {
+        base: StubPlayer,
+        attacked: Arc<Mutex<Vec<EntityId>>>,
+    }
+
+    impl FlintPlayer for AttackingPlayer {
+        delegate_player!();
+
+        fn attack_entity(&mut self, entity: EntityId) -> bool {
+            self.attacked.lock().unwrap().push(entity);
+            true
+        }
+    }
+
+    /// World with a redstone component at the origin
+    #[derive(Clone, Default)]
+    struct SignalWorld {
+        base: StubWorld,
+        /// Signal received through each face
+        received: HashMap<BlockFace, u8>,
+        /// Strong signal emitted through each face
+        strong: HashMap<BlockFace, u8>,
+    }
+
+    impl FlintWorld for SignalWorld {
+        delegate_world!();
+
+        fn direct_signal(&self, _pos: BlockPos, face: &BlockFace) -> Option<u8> {
+            Some(self.strong.get(face).copied().unwrap_or(0))
+        }
+
+        fn indirect_signal(&self, _pos: BlockPos, face: Option<&BlockFace>) -> Option<u8> {
+            let signal = match face {
+                Some(face) => self.received.get(face).copied(),
+                None => self.received.values().max().copied(),
+            };
+            Some(signal.unwrap_or(0))
+        }
+    }
+
+    #[test]
+    fn test_assert_unknown_tag() {
+        let spec = spec(
+            "{}",
+            &[
+                r##"{"at": 0, "do": "assert", "checks": [{"pos": [0, 0, 0], "is": {"id": "#minecraft:logs"}}]}"##,
+            ],
+        );
+        let result = run(StubWorld::default(), &spec);
+        assert!(!result.success);
+        assert_eq!(
+            failure_message(&result),
+            Some("Cannot check unknown or empty block tag '#minecraft:logs'")
+        );
+
+        // An empty tag matches nothing, so it is reported like an unknown one
+        let adapter = StubAdapter::new(StubWorld::default()).with_tag("minecraft:logs", &[]);
+        let result = Arc::new(adapter).run(&spec);
+        assert_eq!(
+            failure_message(&result),
+            Some("Cannot check unknown or empty block tag '#minecraft:logs'")
+        );
+    }
+
+    #[test]
+    fn test_place_tag() {
+        let adapter = StubAdapter::new(StubWorld::default())
+            .with_tag("minecraft:logs", &["minecraft:oak_log"]);
+        let adapter = Arc::new(adapter);
+        for place in [
+            r##"{"at": 0, "do": "place", "pos": [0, 0, 0], "block": {"id": "#minecraft:logs"}}"##,
+            r##"{"at": 0, "do": "place_each", "blocks": [{"pos": [0, 0, 0], "block": {"id": "#logs"}}]}"##,
+            r##"{"at": 0, "do": "fill", "region": [[0, 0, 0], [1, 0, 0]], "with": {"id": "#logs"}}"##,
+        ] {
+            let result = adapter.run(&spec("{}", &[place]));
+            assert!(!result.success);
+            assert_eq!(
+                failure_message(&result),
+                Some("Cannot place block tag '#minecraft:logs', use a block id")
+            );
+        }
+    }
+
+    #[test]
+    fn test_set_container_rejected() {
+        let world = ContainerWorld {
+            read_only: true,
+            ..Default::default()
+        };
+        let spec = spec(
+            "{}",
+            &[
+                r#"{"at": 0, "do": "set_container", "pos": [0, 0, 0], "items": [{"slot": 0, "id": "minecraft:coal"}]}"#,
+            ],
+        );
+        let result = run(world, &spec);
+        assert!(!result.success);
+        assert_eq!(
+            failure_message(&result),
+            Some("Failed to set container slot 0 at [0, 0, 0] to 'empty'")
+        );
+    }
+
+    #[test]
+    fn test_assert_container_slot_out_of_range() {
+        let spec = spec(
+            "{}",
+            &[
+                r#"{"at": 0, "do": "assert_container", "pos": [0, 0, 0], "slots": [{"slot": 5, "id": "minecraft:air"}]}"#,
+            ],
+        );
+        let result = run(ContainerWorld::default(), &spec);
+        assert!(!result.success);
+        assert_eq!(
+            failure_message(&result),
+            Some("Container at [0, 0, 0] has 3 slots, cannot check slot 5")
+        );
+    }
+
+    #[test]
+    fn test_assert_container_contains_components() {
+        let enchantments = serde_json::from_str(r#"{"minecraft:silk_touch": 1}"#).unwrap();
+        let pickaxe = Item::new("minecraft:diamond_pickaxe")
+            .with_component("minecraft:enchantments", enchantments);
+        let world = ContainerWorld {
+            slots: [Some(pickaxe), None, None],
+            ..Default::default()
+        };
+        let contains = |components: &str| {
+            let action = format!(
+                r#"{{"at": 0, "do": "assert_container", "pos": [0, 0, 0], "contains": [{{"id": "diamond_pickaxe", {}}}]}}"#,
+                components
+            );
+            run(world.clone(), &spec("{}", &[&action]))
+        };
+        assert!(contains(r#""enchantments": {"silk_touch": 1}"#).success);
+        assert!(!contains(r#""enchantments": {"fortune": 1}"#).success);
+        assert!(!contains(r#""damage": 0"#).success);
+    }
+
+    #[test]
+    fn test_assert_container_contains_at_least() {
+        let world = ContainerWorld {
+            slots: [
+                Some(Item::with_count("minecraft:coal", 5)),
+                None,
+                Some(Item::with_count("minecraft:coal", 3)),
+            ],
+            ..Default::default()
+        };
+        let contains = |count: u8| {
+            let action = format!(
+                r#"{{"at": 0, "do": "assert_container", "pos": [0, 0, 0], "contains": [{{"id": "coal", "count": {}}}]}}"#,
+                count
+            );
+            run(world.clone(), &spec("{}", &[&action]))
+        };
+        assert!(contains(7).success);
+        assert!(contains(8).success);
+        let result = contains(9);
+        assert!(!result.success);
+        assert_eq!(
+            failure_message(&result),
+            Some("Container at [0, 0, 0]: expected at least 9 of 'coal', found 8")
+        );
+    }
+
+    #[test]
+    fn test_entities_in_reversed_region() {
+        let world = EntityWorld {
+            entities: vec![
+                StubEntity::new(1, "minecraft:pig", [1.5, 0.0, 1.5]),
+                StubEntity::item(2, Item::with_count("minecraft:stone", 2), [2.5, 0.0, 2.5]),
+            ],
+            ..Default::default()
+        };
+        let spec = spec(
+            "{}",
+            &[
+                r#"{"at": 0, "do": "assert_entities", "region": [[3, 1, 3], [0, 0, 0]], "entity": "pig", "count": 1}"#,
+                r#"{"at": 0, "do": "assert_drops", "region": [[3, 1, 3], [0, 0, 0]], "items": [{"id": "stone", "count": 2}]}"#,
+                r#"{"at": 1, "do": "kill_entities", "region": [[3, 1, 3], [0, 0, 0]], "entity": "pig"}"#,
+                r#"{"at": 2, "do": "assert_entities", "region": [[3, 1, 3], [0, 0, 0]], "entity": "pig", "count": 0}"#,
+            ],
+        );
+        let result = run(world, &spec);
+        assert!(result.success, "{:?}", failure_message(&result));
+    }
+
+    #[test]
+    fn test_assert_power() {
+        let world = SignalWorld {
+            received: HashMap::from([(BlockFace::North, 3), (BlockFace::West, 7)]),
+            strong: HashMap::from([(BlockFace::Top, 15)]),
+            ..Default::default()
+        };
+        let assert_power = |check: &str| {
+            let action = format!(
+                r#"{{"at": 0, "do": "assert_power", "pos": [0, 0, 0], {}}}"#,
+                check
+            );
+            run(world.clone(), &spec("{}", &[&action]))
+        };
+        assert!(assert_power(r#""power": 7"#).success);
+        assert!(assert_power(r#""face": "north", "power": 3"#).success);
+        assert!(assert_power(r#""face": "top", "power": 0"#).success);
+        assert!(assert_power(r#""face": "top", "strong": true, "power": 15"#).success);
+        assert!(assert_power(r#""face": "west", "strong": true, "power": 0"#).success);
+
+        let result = assert_power(r#""face": "north", "power": {"min": 4}"#);
+        assert!(!result.success);
+        assert_eq!(
+            failure_message(&result),
+            Some(
+                "Power mismatch at [0, 0, 0] (signal received through North face): expected >= 4, got 3"
+            )
+        );
+
+        let result = run(
+            StubWorld::default(),
+            &spec(
+                "{}",
+                &[r#"{"at": 0, "do": "assert_power", "pos": [0, 0, 0], "power": 0}"#],
+            ),
+        );
+        assert_eq!(
+            failure_message(&result),
+            Some("Adapter does not support redstone signal queries")
+        );
+    }
+
+    #[test]
+    fn test_teleport() {
+        let world = StubWorld::with_players(|| Box::new(MobilePlayer::default()));
+        let teleport = r#"{"at": 0, "do": "teleport", "pos": [1.5, 2, 3.5]}"#;
+        let assert_player = |pos: &str, tolerance: f64| {
+            let check = format!(
+                r#"{{"at": 1, "do": "assert_player", "pos": {}, "tolerance": {}}}"#,
+                pos, tolerance
+            );
+            run(world.clone(), &spec("{}", &[teleport, &check]))
+        };
+        assert!(assert_player("[1.5, 2, 3.5]", 0.0).success);
+        // The tolerance is inclusive
+        assert!(assert_player("[2, 2, 3.5]", 0.5).success);
+        let result = assert_player("[2, 2, 3.5]", 0.4);
+        assert!(!result.success);
+        assert_eq!(
+            failure_message(&result),
+            Some(
+                "Player position mismatch: expected (2, 2, 3.5) within 0.4, got (1.50, 2.00, 3.50)"
+            )
+        );
+    }
+
+    #[test]
+    fn test_teleport_unsupported() {
+        let teleport = r#"{"at": 0, "do": "teleport", "pos": [1.5, 2, 3.5]}"#;
+        let result = run(StubWorld::default(), &spec("{}", &[teleport]));
+        assert!(!result.success);
+        assert_eq!(
+            failure_message(&result),
+            Some("Adapter does not support player positioning")
+        );
+        assert_eq!(failure(&result).unwrap().position, [1, 2, 3]);
+
+        let check = r#"{"at": 0, "do": "assert_player", "pos": [0, 0, 0]}"#;
+        let result = run(StubWorld::default(), &spec("{}", &[check]));
+        assert_eq!(
+            failure_message(&result),
+            Some("Adapter does not support player positioning")
+        );
+    }
+
+    #[test]
+    fn test_hold_item_off_hand() {
+        let spec = spec(
+            "{}",
+            &[
+                r#"{"at": 0, "do": "set_slot", "slot": "hotbar3", "item": "minecraft:stone"}"#,
+                r#"{"at": 0, "do": "select_hotbar", "slot": 3}"#,
+                r#"{"at": 1, "do": "use_item_on", "pos": [0, 0, 0], "face": "top", "item": "minecraft:shield", "hand": "off"}"#,
+                r#"{"at": 2, "do": "assert_slot", "slot": "off_hand", "item": "minecraft:shield"}"#,
+                r#"{"at": 2, "do": "assert_slot", "slot": "hotbar1"}"#,
+                r#"{"at": 2, "do": "assert_slot", "slot": "hotbar3", "item": "minecraft:stone"}"#,
+                r#"{"at": 2, "do": "assert_selected_hotbar", "slot": 3}"#,
+            ],
+        );
+        let result = run(StubWorld::default(), &spec);
+        assert!(result.success, "{:?}", failure_message(&result));
+    }
+
+    #[test]
+    fn test_attack_nearest_entity() {
+        let attacked = Arc::new(Mutex::new(Vec::new()));
+        let player_attacked = attacked.clone();
+        let world = EntityWorld {
+            base: StubWorld::with_players(move || {
+                Box::new(AttackingPlayer {
+                    attacked: player_attacked.clone(),
+                    ..Default::default()
+                })
+            }),
+            entities: vec![
+                StubEntity::new(1, "minecraft:pig", [2.5, 1.0, 3.5]),
+                StubEntity::new(2, "minecraft:pig", [1.9, 1.0, 3.5]),
+                StubEntity::new(3, "minecraft:cow", [2.5, 1.0, 3.6]),
+            ],
+        };
+        let attack = r#"{"at": 0, "do": "attack_entity", "entity": "pig", "pos": [1.5, 1, 3.5], "tolerance": 2}"#;
+        let result = run(world.clone(), &spec("{}", &[attack]));
+        assert!(result.success, "{:?}", failure_message(&result));
+        assert_eq!(*attacked.lock().unwrap(), vec![2]);
+
+        let attack = r#"{"at": 0, "do": "attack_entity", "entity": "sheep", "pos": [1.5, 1, 3.5], "tolerance": 2}"#;
+        let result = run(world, &spec("{}", &[attack]));
+        assert!(!result.success);
+        let fail = failure(&result).unwrap();
+        assert_eq!(
+            fail.error_message,
+            "No entity found: expected sheep within 2 of (1.5, 1, 3.5)"
+        );
+        assert_eq!(fail.position, [1, 1, 3]);
+        assert_eq!(attacked.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_use_item_unsupported() {
+        let world = StubWorld::with_players(|| Box::new(MobilePlayer::default()));
+        let spec = spec(
+            "{}",
+            &[
+                r#"{"at": 0, "do": "teleport", "pos": [1.5, 2, 3.5]}"#,
+                r#"{"at": 1, "do": "use_item", "item": "minecraft:bread"}"#,
+            ],
+        );
+        let result = run(world, &spec);
+        assert!(!result.success);
+        let fail = failure(&result).unwrap();
+        assert_eq!(fail.error_message, "Adapter does not support using items");
+        assert_eq!(fail.position, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_named_players() {
+        let world = StubWorld::with_players(|| Box::new(MobilePlayer::default()));
+        let setup = r#"{"players": {
+            "a": {"inventory": {"hotbar2": {"id": "minecraft:stone"}}, "selected_hotbar": 2, "position": [1.5, 0, 1.5]},
+            "b": {"selected_hotbar": 5, "position": [3.5, 0, 3.5]}
+        }}"#;
+        let spec = spec(
+            setup,
+            &[
+                r#"{"at": 0, "player": "a", "do": "select_hotbar", "slot": 7}"#,
+                r#"{"at": 1, "player": "a", "do": "assert_selected_hotbar", "slot": 7}"#,
+                r#"{"at": 1, "player": "b", "do": "assert_selected_hotbar", "slot": 5}"#,
+                r#"{"at": 1, "do": "assert_selected_hotbar", "slot": 1}"#,
+                r#"{"at": 1, "player": "a", "do": "assert_slot", "slot": "hotbar2", "item": "minecraft:stone"}"#,
+                r#"{"at": 1, "player": "b", "do": "assert_slot", "slot": "hotbar2"}"#,
+                r#"{"at": 1, "player": "a", "do": "assert_player", "pos": [1.5, 0, 1.5]}"#,
+                r#"{"at": 1, "player": "b", "do": "assert_player", "pos": [3.5, 0, 3.5]}"#,
+            ],
+        );
+        let result = run(world, &spec);
+        assert!(result.success, "{:?}", failure_message(&result));
+        assert_eq!(result.assertions.len(), 7);
+    }
+
+    #[test]
+    fn test_undeclared_player() {
+        let spec = spec(
+            r#"{"players": {"a": {}}}"#,
+            &[r#"{"at": 0, "player": "c", "do": "select_hotbar", "slot": 2}"#],
+        );
+        let result = run(StubWorld::default(), &spec);
+        assert!(!result.success);
+        assert_eq!(
+            result.failure_reason.as_deref(),
+            Some("Unknown player 'c', declare it in setup.players")
+        );
+    }
+
+    #[test]
+    fn test_game_mode() {
+        let world = StubWorld::with_players(|| Box::new(GameModePlayer::default()));
+        let check = |setup: &str, game_mode: &str| {
+            let check = format!(
+                r#"{{"at": 0, "do": "assert_player", "game_mode": "{}"}}"#,
+                game_mode
+            );
+            run(world.clone(), &spec(setup, &[&check]))
+        };
+        assert!(check("{}", "survival").success);
+        assert!(check(r#"{"player": {"game_mode": "creative"}}"#, "creative").success);
+
+        let result = check(r#"{"player": {"game_mode": "adventure"}}"#, "creative");
+        assert!(!result.success);
+        assert_eq!(
+            failure_message(&result),
+            Some("Player game mode mismatch: expected creative, got adventure")
+        );
+    }
+
+    #[test]
+    fn test_game_mode_unsupported() {
+        let setup = r#"{"player": {"game_mode": "creative"}}"#;
+        let result = run(StubWorld::default(), &spec(setup, &[]));
+        assert!(!result.success);
+        assert_eq!(
+            result.failure_reason.as_deref(),
+            Some("Adapter does not support player game modes")
+        );
+
+        let check = r#"{"at": 0, "do": "assert_player", "game_mode": "survival"}"#;
+        let result = run(StubWorld::default(), &spec("{}", &[check]));
+        assert!(!result.success);
+        assert_eq!(
+            failure_message(&result),
+            Some("Adapter does not support player game modes")
+        );
+    }
+
+    #[test]
+    fn test_assert_scheduled() {
+        let schedule = [
+            r#"{"at": 0, "do": "schedule_tick", "pos": [0, 0, 0], "target": "minecraft:repeater", "delay": 2, "priority": -1}"#,
+            r#"{"at": 0, "do": "schedule_tick", "pos": [0, 0, 0], "target": "minecraft:repeater", "delay": 4}"#,
+        ];
+        let check = |check: &str| {
+            let check = format!(
+                r#"{{"at": 0, "do": "assert_scheduled", "pos": [0, 0, 0], {}}}"#,
+                check
+            );
+            let timeline = [schedule[0], schedule[1], &check];
+            run(TickWorld::default(), &spec("{}", &timeline))
+        };
+        assert!(check(r#""count": 2"#).success);
+        assert!(check(r#""target": "observer", "count": 0"#).success);
+        assert!(!check(r#""count": 0"#).success);
+        assert!(check(r#""priority": -1, "count": 1"#).success);
+        assert!(check(r#""priority": 0, "delay": 4"#).success);
+        assert!(check(r#""delay": {"min": 3}, "count": 1"#).success);
+        assert!(check(r#""delay": 2, "priority": -1"#).success);
+
+        let result = check(r#""delay": 2, "priority": 0"#);
+        assert!(!result.success);
+        assert_eq!(
+            failure_message(&result),
+            Some(
+                "Scheduled tick mismatch at [0, 0, 0]: expected a scheduled tick with delay 2 \
+                 with priority 0, got 0 matching; pending: [minecraft:repeater in 2 (priority -1), \
+                 minecraft:repeater in 4 (priority 0)]"
+            )
+        );
+    }
+
+    #[test]
+    fn test_random_tick() {
+        let spec = spec(
+            "{}",
+            &[
+                r#"{"at": 0, "do": "place", "pos": [0, 0, 0], "block": {"id": "minecraft:copper_block"}}"#,
+                r#"{"at": 1, "do": "random_tick", "pos": [0, 0, 0]}"#,
+                r#"{"at": 2, "do": "assert", "checks": [{"pos": [0, 0, 0], "is": {"id": "minecraft:exposed_copper"}}]}"#,
+            ],
+        );
+        let result = run(TickWorld::default(), &spec);
+        assert!(result.success, "{:?}", failure_message(&result));
+
+        let result = run(StubWorld::default(), &spec);
+        assert_eq!(
+            failure_message(&result),
+            Some("Adapter does not support random ticks")
+        );
+    }
+
+    #[test]
+    fn test_assert_light() {
+        let world = LightWorld {
+            block_light: 14,
+            sky_light: 15,
+            ..Default::default()
+        };
+        let check = |light: &str| {
+            let check = format!(
+                r#"{{"at": 0, "do": "assert_light", "pos": [1, 2, 3], {}}}"#,
+                light
+            );
+            run(world.clone(), &spec("{}", &[&check]))
+        };
+        assert!(check(r#""block": 14, "sky": 15"#).success);
+        assert!(check(r#""block": {"min": 10}"#).success);
+
+        let result = check(r#""block": 15"#);
+        assert!(!result.success);
+        assert_eq!(
+            failure_message(&result),
+            Some("Light mismatch at [1, 2, 3] (block light): expected 15, got 14")
+        );
+        let result = check(r#""block": 14, "sky": {"max": 7}"#);
+        assert!(!result.success);
+        assert_eq!(
+            failure_message(&result),
+            Some("Light mismatch at [1, 2, 3] (sky light): expected <= 7, got 15")
+        );
+
+        let check = r#"{"at": 0, "do": "assert_light", "pos": [1, 2, 3], "sky": 15}"#;
+        let result = run(StubWorld::default(), &spec("{}", &[check]));
+        assert!(!result.success);
+        assert_eq!(
+            failure_message(&result),
+            Some("Adapter does not support light queries")
+        );
+    }
+
+    #[test]
+    fn test_setup_game_rule_rejected() {
+        let spec = spec(r#"{"game_rules": {"unknownRule": 3}}"#, &[]);
+        let result = run(SettingsWorld::default(), &spec);
+        assert!(!result.success);
+        assert_eq!(
+            result.failure_reason.as_deref(),
+            Some(
+                "Cannot set game rule 'unknownRule' to 3: the rule is unknown, \
+                 the value has the wrong type or the adapter does not support game rules"
+            )
+        );
+    }
+
+    #[test]
+    fn test_break_block_unsupported() {
+        let spec = spec(
+            "{}",
+            &[r#"{"at": 0, "do": "break_block", "pos": [0, 0, 0]}"#],
+        );
+        let result = run(StubWorld::default(), &spec);
+        assert!(!result.success);
+        assert_eq!(
+            failure_message(&result),
+            Some("Adapter does not support breaking blocks")
+        );
+    }
+
+    #[test]
+    fn test_unsupported_world_option() {
+        let seed = spec(
+            r#"{"world": {"seed": 42}}"#,
+            &[r#"{"at": 0, "do": "remove", "pos": [0, 0, 0]}"#],
+        );
+        let adapter = Arc::new(StubAdapter::new(StubWorld::default()));
+        let result = adapter.run(&seed);
+        assert!(!result.success);
+        assert_eq!(
+            result.failure_reason.as_deref(),
+            Some("Adapter does not support world option seed")
+        );
+        assert!(result.assertions.is_empty());
+        assert!(adapter.configs.lock().unwrap().is_empty());
+
+        // Every requested option must be supported
+        let both = spec(r#"{"world": {"seed": 42, "random_tick_speed": 0}}"#, &[]);
+        let adapter = StubAdapter::new(StubWorld::default()).with_options(&[WorldOption::Seed]);
+        let adapter = Arc::new(adapter);
+        let result = adapter.run(&both);
+        assert_eq!(
+            result.failure_reason.as_deref(),
+            Some("Adapter does not support world option random_tick_speed")
+        );
+        assert!(adapter.configs.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_world_config() {
+        let spec = spec(r#"{"world": {"seed": 42, "random_tick_speed": 0}}"#, &[]);
+        let adapter = StubAdapter::new(StubWorld::default())
+            .with_options(&[WorldOption::Seed, WorldOption::RandomTickSpeed]);
+        let adapter = Arc::new(adapter);
+        let result = adapter.run(&spec);
+        assert!(result.success, "{:?}", result.failure_reason);
+
+        let expected = WorldConfig {
+            seed: Some(42),
+            random_tick_speed: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(*adapter.configs.lock().unwrap(), vec![expected]);
+    }
+
+    #[test]
+    fn test_default_set_block_with() {
+        // `StubWorld` only implements `set_block`
+        let place = |mode: &str| {
+            let place = format!(
+                r#"{{"at": 0, "do": "place", "pos": [0, 0, 0], "block": {{"id": "minecraft:stone"}}, "mode": "{}"}}"#,
+                mode
+            );
+            let check = r#"{"at": 1, "do": "assert", "checks": [{"pos": [0, 0, 0], "is": {"id": "minecraft:stone"}}]}"#;
+            run(StubWorld::default(), &spec("{}", &[&place, check]))
+        };
+        assert!(place("update").success);
+
+        let silent = place("silent");
+        assert!(!silent.success);
+        assert_eq!(
+            failure_message(&silent),
+            Some("Adapter does not support silent block placement")
+        );
+    }
+
+    #[test]
+    fn test_world_settings_restore() {
+        let mut world = SettingsWorld::new(Settings {
+            day_time: 1000,
+            game_rules: BTreeMap::from([("doFireTick".to_string(), GameRuleValue::Bool(true))]),
+            ..Default::default()
+        });
+        let mut settings = WorldSettings::default();
+
+        assert!(settings.set_day_time(&mut world, 13000));
+        assert!(settings.set_day_time(&mut world, 18000));
+        assert!(settings.set_weather(&mut world, Weather::Thunder));
+        assert!(settings.set_game_rule(&mut world, "doFireTick", &GameRuleValue::Bool(false)));
+        assert!(settings.set_biome(&mut world, [[5, 0, 5], [2, 0, 2]], "minecraft:snowy_plains"));
+        assert_eq!(world.settings().day_time, 18000);
+        assert_eq!(
+            world.get_biome([3, 0, 4]).as_deref(),
+            Some("minecraft:snowy_plains")
+        );
+
+        settings.restore(&mut world);
+        assert_eq!(world.settings().day_time, 1000);
+        assert_eq!(world.settings().weather, Weather::Clear);
+        assert_eq!(
+            world.game_rule("doFireTick"),
+            Some(GameRuleValue::Bool(true))
+        );
+        assert_eq!(
+            world.get_biome([3, 0, 4]).as_deref(),
+            Some("minecraft:plains")
+        );
+    }
+
+    #[test]
+    fn test_world_settings_restore_on_reused_world() {
+        let world = SettingsWorld::new(Settings {
+            day_time: 1000,
+            game_rules: BTreeMap::from([("doFireTick".to_string(), GameRuleValue::Bool(true))]),
+            ..Default::default()
+        });
+        let adapter = Arc::new(StubAdapter::new(world.clone()));
+
+        // The changes are undone even though the test fails
+        let changes = spec(
+            r#"{"time": 13000, "weather": "thunder", "game_rules": {"doFireTick": false}}"#,
+            &[
+                r#"{"at": 0, "do": "set_time", "time": 18000}"#,
+                r#"{"at": 0, "do": "set_biome", "region": [[5, 0, 5], [2, 0, 2]], "biome": "minecraft:snowy_plains"}"#,
+                r#"{"at": 1, "do": "assert_biome", "pos": [3, 0, 4], "biome": "minecraft:desert"}"#,
+            ],
+        );
+        let result = adapter.run(&changes);
+        assert_eq!(
+            failure_message(&result),
+            Some(
+                "Biome mismatch at [3, 0, 4]: expected minecraft:desert, got minecraft:snowy_plains"
+            )
+        );
+        assert_eq!(world.settings().day_time, 1000);
+        assert_eq!(world.settings().weather, Weather::Clear);
+        assert_eq!(
+            world.game_rule("doFireTick"),
+            Some(GameRuleValue::Bool(true))
+        );
+
+        let next = spec(
+            "{}",
+            &[r#"{"at": 0, "do": "assert_biome", "pos": [3, 0, 4], "biome": "minecraft:plains"}"#],
+        );
+        let result = adapter.run(&next);
+        assert!(result.success, "{:?}", failure_message(&result));
+    }
+
+    fn event(kind: EventKind, pos: BlockPos, id: &str) -> WorldEvent {
+        WorldEvent {
+            tick: 0,
+            kind,
+            pos,
+            id: id.to_string(),
+        }
+    }
+
+    fn matcher(kind: EventKind, pos: Option<BlockPos>) -> EventMatcher {
+        EventMatcher {
+            kind,
+            pos,
+            id: None,
+        }
+    }
+
+    #[test]
+    fn test_assert_events_range() {
+        let world = EventWorld {
+            events: vec![
+                WorldEvent {
+                    tick: 1,
+                    ..event(EventKind::BlockUpdate, [1, 0, 1], "minecraft:repeater")
+                },
+                WorldEvent {
+                    tick: 3,
+                    ..event(EventKind::BlockUpdate, [2, 0, 2], "minecraft:observer")
+                },
+            ],
+            ..Default::default()
+        };
+        let check = |range: &str| {
+            let check = format!(
+                r#"{{"at": 5, "do": "assert_events", "region": [[3, 1, 3], [0, 0, 0]], {}, "events": [{{"kind": "block_update", "pos": [2, 0, 2]}}], "absent": [{{"kind": "block_update", "pos": [1, 0, 1]}}]}}"#,
+                range
+            );
+            run(world.clone(), &spec("{}", &[&check]))
+        };
+        assert!(check(r#""since": 2"#).success);
+        assert!(check(r#""since": 2, "until": 3"#).success);
+        assert_eq!(
+            *world.captured_region.lock().unwrap(),
+            Some([[0, 0, 0], [3, 1, 3]])
+        );
+
+        let result = check(r#""since": 2, "until": 2"#);
+        assert!(!result.success);
+        assert_eq!(
+            failure_message(&result),
+            Some(
+                "Event missing in region [[0, 0, 0], [3, 1, 3]]: expected block_update at [2, 0, 2], got no events"
+            )
+        );
+        assert!(!check(r#""until": 3"#).success);
+    }
+
+    #[test]
+    fn test_missing_event_order() {
+        let events = [
+            event(EventKind::BlockUpdate, [1, 0, 0], "minecraft:repeater"),
+            event(EventKind::ShapeUpdate, [0, 1, 0], "minecraft:redstone_wire"),
+            event(
+                EventKind::Sound,
+                [1, 0, 0],
+                "minecraft:block.note_block.harp",
+            ),
+        ];
+        let update = matcher(EventKind::BlockUpdate, Some([1, 0, 0]));
+        let sound = EventMatcher {
+            id: Some("block.note_block.harp".to_string()),
+            ..matcher(EventKind::Sound, None)
+        };
+
+        let expected = [update.clone(), sound.clone()];
+        assert_eq!(missing_event(&expected, &events, true), None);
+        assert_eq!(missing_event(&expected, &events, false), None);
+
+        let reversed = [sound, update];
+        assert_eq!(missing_event(&reversed, &events, true), Some(1));
+        assert_eq!(missing_event(&reversed, &events, false), None);
+
+        let wrong_pos = [matcher(EventKind::BlockUpdate, Some([2, 0, 0]))];
+        assert_eq!(missing_event(&wrong_pos, &events, false), Some(0));
+    }
+
+    #[test]
+    fn test_drops_diff() {
+        let expected = BTreeMap::from([
+            ("minecraft:gravel".to_string(), 1),
+            ("minecraft:flint".to_string(), 1),
+        ]);
+        let actual = BTreeMap::from([
+            ("minecraft:gravel".to_string(), 2),
+            ("minecraft:stone".to_string(), 1),
+        ]);
+        assert_eq!(
+            drops_diff(&expected, &actual),
+            "missing minecraft:flint x1; unexpected minecraft:gravel x1, minecraft:stone x1"
+        );
+        assert_eq!(format_drops(&BTreeMap::new()), "no drops");
+    }
+
+    #[test]
+    fn test_block_matches_tag() {
+        let logs = vec!["minecraft:oak_log".to_string(), "birch_log".to_string()];
+        let tag = Block::new("#minecraft:logs");
+
+        assert!(block_matches(&Block::new("oak_log"), &tag, Some(&logs)));
+        assert!(block_matches(
+            &Block::new("minecraft:birch_log"),
+            &tag,
+            Some(&logs)
+        ));
+        assert!(!block_matches(
+            &Block::new("minecraft:stone"),
+            &tag,
+            Some(&logs)
+        ));
+        // Unknown tags never match
+        assert!(!block_matches(&Block::new("minecraft:oak_log"), &tag, None));
+    }
+
+    #[test]
+    fn test_block_matches_tag_with_properties() {
+        let logs = vec!["minecraft:oak_log".to_string()];
+        let mut tag = Block::new("#minecraft:logs");
+        tag.properties.insert("axis".to_string(), "y".to_string());
+
+        let mut actual = Block::new("minecraft:oak_log");
+        actual
+            .properties
+            .insert("axis".to_string(), "x".to_string());
+        assert!(!block_matches(&actual, &tag, Some(&logs)));
+
+        actual
+            .properties
+            .insert("axis".to_string(), "y".to_string());
+        assert!(block_matches(&actual, &tag, Some(&logs)));
+    }
 }
@@ -1,7 +1,8 @@
+use crate::nbt::Nbt;
 use rustc_hash::FxHashMap;
 use serde::de::{MapAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Formatter;
 use std::path::PathBuf;
 
@@ -31,6 +32,151 @@ pub struct SetupSpec {
     pub cleanup: Option<CleanupSpec>,
     #[serde(default)]
     pub player: Option<PlayerConfig>,
+    /// Additional named players, selected with the `player` field of player actions
+    #[serde(default)]
+    pub players: BTreeMap<String, PlayerConfig>,
+    /// Initial day time in ticks (0 = sunrise, 6000 = noon, 13000 = night)
+    #[serde(default)]
+    pub time: Option<u64>,
+    /// Initial weather
+    #[serde(default)]
+    pub weather: Option<Weather>,
+    /// Game rules to set before the first tick, e.g. `{"doFireTick": false, "randomTickSpeed": 0}`
+    #[serde(default)]
+    pub game_rules: BTreeMap<String, GameRuleValue>,
+    /// Options used to create the test world
+    #[serde(default)]
+    pub world: WorldConfig,
+}
+
+/// Options for creating a test world, passed to `FlintAdapter::create_test_world`
+///
+/// Unset options leave the choice to the adapter.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorldConfig {
+    /// Seed for the world RNG, making random behavior reproducible
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// Random ticks per chunk section per game tick (vanilla default 3, 0 disables random ticks)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub random_tick_speed: Option<u32>,
+    /// Dimension type, e.g. "minecraft:the_nether"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dimension: Option<String>,
+    /// Superflat layers from the bottom up, the last layer is the ground
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layers: Option<Vec<FlatLayer>>,
+    /// Lowest buildable Y level
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_y: Option<i32>,
+    /// Highest buildable Y level
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_y: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<Difficulty>,
+}
+
+impl WorldConfig {
+    /// Options set in this config, to check against what the adapter supports
+    pub fn requested_options(&self) -> Vec<WorldOption> {
+        let mut options = Vec::new();
+        if self.seed.is_some() {
+            options.push(WorldOption::Seed);
+        }
+        if self.random_tick_speed.is_some() {
+            options.push(WorldOption::RandomTickSpeed);
+        }
+        if self.dimension.is_some() {
+            options.push(WorldOption::Dimension);
+        }
+        if self.layers.is_some() {
+            options.push(WorldOption::Layers);
+        }
+        if self.min_y.is_some() || self.max_y.is_some() {
+            options.push(WorldOption::BuildHeight);
+        }
+        if self.difficulty.is_some() {
+            options.push(WorldOption::Difficulty);
+        }
+        options
+    }
+}
+
+/// A configurable aspect of world creation, see [`WorldConfig`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WorldOption {
+    Seed,
+    RandomTickSpeed,
+    Dimension,
+    Layers,
+    /// `min_y` and `max_y`
+    BuildHeight,
+    Difficulty,
+}
+
+impl std::fmt::Display for WorldOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            WorldOption::Seed => "seed",
+            WorldOption::RandomTickSpeed => "random_tick_speed",
+            WorldOption::Dimension => "dimension",
+            WorldOption::Layers => "layers",
+            WorldOption::BuildHeight => "build height",
+            WorldOption::Difficulty => "difficulty",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A superflat layer, e.g. `{"block": "minecraft:dirt", "height": 3}`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlatLayer {
+    pub block: String,
+    /// Layer thickness in blocks (default 1)
+    #[serde(default = "default_layer_height")]
+    pub height: u32,
+}
+
+fn default_layer_height() -> u32 {
+    1
+}
+
+/// World difficulty
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Difficulty {
+    Peaceful,
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+/// World weather
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Weather {
+    #[default]
+    Clear,
+    Rain,
+    Thunder,
+}
+
+/// Value of a game rule, either a boolean or an integer rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GameRuleValue {
+    Bool(bool),
+    Int(i32),
+}
+
+impl std::fmt::Display for GameRuleValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameRuleValue::Bool(b) => write!(f, "{}", b),
+            GameRuleValue::Int(i) => write!(f, "{}", i),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,20 +184,87 @@ pub struct CleanupSpec {
     pub region: [[i32; 3]; 2],
 }
 /// Player inventory slots
+///
+/// Serialized as `hotbar1`, `inventory27`, `off_hand`, ... (`hotbar_1` style is accepted too).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PlayerSlot {
     // Hotbar (9 slots)
+    #[serde(alias = "hotbar_1")]
     Hotbar1,
+    #[serde(alias = "hotbar_2")]
     Hotbar2,
+    #[serde(alias = "hotbar_3")]
     Hotbar3,
+    #[serde(alias = "hotbar_4")]
     Hotbar4,
+    #[serde(alias = "hotbar_5")]
     Hotbar5,
+    #[serde(alias = "hotbar_6")]
     Hotbar6,
+    #[serde(alias = "hotbar_7")]
     Hotbar7,
+    #[serde(alias = "hotbar_8")]
     Hotbar8,
+    #[serde(alias = "hotbar_9")]
     Hotbar9,
 
+    // Main inventory (27 slots, top-left to bottom-right)
+    #[serde(alias = "inventory_1")]
+    Inventory1,
+    #[serde(alias = "inventory_2")]
+    Inventory2,
+    #[serde(alias = "inventory_3")]
+    Inventory3,
+    #[serde(alias = "inventory_4")]
+    Inventory4,
+    #[serde(alias = "inventory_5")]
+    Inventory5,
+    #[serde(alias = "inventory_6")]
+    Inventory6,
+    #[serde(alias = "inventory_7")]
+    Inventory7,
+    #[serde(alias = "inventory_8")]
+    Inventory8,
+    #[serde(alias = "inventory_9")]
+    Inventory9,
+    #[serde(alias = "inventory_10")]
+    Inventory10,
+    #[serde(alias = "inventory_11")]
+    Inventory11,
+    #[serde(alias = "inventory_12")]
+    Inventory12,
+    #[serde(alias = "inventory_13")]
+    Inventory13,
+    #[serde(alias = "inventory_14")]
+    Inventory14,
+    #[serde(alias = "inventory_15")]
+    Inventory15,
+    #[serde(alias = "inventory_16")]
+    Inventory16,
+    #[serde(alias = "inventory_17")]
+    Inventory17,
+    #[serde(alias = "inventory_18")]
+    Inventory18,
+    #[serde(alias = "inventory_19")]
+    Inventory19,
+    #[serde(alias = "inventory_20")]
+    Inventory20,
+    #[serde(alias = "inventory_21")]
+    Inventory21,
+    #[serde(alias = "inventory_22")]
+    Inventory22,
+    #[serde(alias = "inventory_23")]
+    Inventory23,
+    #[serde(alias = "inventory_24")]
+    Inventory24,
+    #[serde(alias = "inventory_25")]
+    Inventory25,
+    #[serde(alias = "inventory_26")]
+    Inventory26,
+    #[serde(alias = "inventory_27")]
+    Inventory27,
+
     // Off-hand
     OffHand,
 
@@ -60,9 +273,59 @@ pub enum PlayerSlot {
     Chestplate,
     Leggings,
     Boots,
+
+    // Item carried on the cursor while a container screen is open
+    #[serde(alias = "carried")]
+    Cursor,
 }
 
 impl PlayerSlot {
+    /// All player slots, hotbar first
+    pub const ALL: [PlayerSlot; 42] = [
+        Self::Hotbar1,
+        Self::Hotbar2,
+        Self::Hotbar3,
+        Self::Hotbar4,
+        Self::Hotbar5,
+        Self::Hotbar6,
+        Self::Hotbar7,
+        Self::Hotbar8,
+        Self::Hotbar9,
+        Self::Inventory1,
+        Self::Inventory2,
+        Self::Inventory3,
+        Self::Inventory4,
+        Self::Inventory5,
+        Self::Inventory6,
+        Self::Inventory7,
+        Self::Inventory8,
+        Self::Inventory9,
+        Self::Inventory10,
+        Self::Inventory11,
+        Self::Inventory12,
+        Self::Inventory13,
+        Self::Inventory14,
+        Self::Inventory15,
+        Self::Inventory16,
+        Self::Inventory17,
+        Self::Inventory18,
+        Self::Inventory19,
+        Self::Inventory20,
+        Self::Inventory21,
+        Self::Inventory22,
+        Self::Inventory23,
+        Self::Inventory24,
+        Self::Inventory25,
+        Self::Inventory26,
+        Self::Inventory27,
+        Self::OffHand,
+        Self::Helmet,
+        Self::Chestplate,
+        Self::Leggings,
+        Self::Boots,
+        Self::Cursor,
+    ];
+
     /// Convert hotbar number (1-9) to PlayerSlot
     pub fn hotbar(n: u8) -> Option<Self> {
         match n {
@@ -78,6 +341,40 @@ impl PlayerSlot {
             _ => None,
         }
     }
+
+    /// Convert main inventory number (1-27) to PlayerSlot
+    pub fn inventory(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Self::Inventory1),
+            2 => Some(Self::Inventory2),
+            3 => Some(Self::Inventory3),
+            4 => Some(Self::Inventory4),
+            5 => Some(Self::Inventory5),
+            6 => Some(Self::Inventory6),
+            7 => Some(Self::Inventory7),
+            8 => Some(Self::Inventory8),
+            9 => Some(Self::Inventory9),
+            10 => Some(Self::Inventory10),
+            11 => Some(Self::Inventory11),
+            12 => Some(Self::Inventory12),
+            13 => Some(Self::Inventory13),
+            14 => Some(Self::Inventory14),
+            15 => Some(Self::Inventory15),
+            16 => Some(Self::Inventory16),
+            17 => Some(Self::Inventory17),
+            18 => Some(Self::Inventory18),
+            19 => Some(Self::Inventory19),
+            20 => Some(Self::Inventory20),
+            21 => Some(Self::Inventory21),
+            22 => Some(Self::Inventory22),
+            23 => Some(Self::Inventory23),
+            24 => Some(Self::Inventory24),
+            25 => Some(Self::Inventory25),
+            26 => Some(Self::Inventory26),
+            27 => Some(Self::Inventory27),
+            _ => None,
+        }
+    }
 }
 
 /// Player configuration for advanced mode (initial inventory setup)
@@ -90,6 +387,63 @@ pub struct PlayerConfig {
     /// Initially selected hotbar slot (1-9), defaults to 1
     #[serde(default = "default_selected_hotbar")]
     pub selected_hotbar: u8,
+    /// Initial position (feet), player stays where the adapter spawns it if not set
+    #[serde(default)]
+    pub position: Option<[f64; 3]>,
+    /// Initial yaw in degrees (0 = south), only used with `position`
+    #[serde(default)]
+    pub yaw: f32,
+    /// Initial pitch in degrees (-90 = up, 90 = down), only used with `position`
+    #[serde(default)]
+    pub pitch: f32,
+    /// Initial game mode, adapter default (survival) if not set
+    #[serde(default)]
+    pub game_mode: Option<GameMode>,
+    /// Initial health (20 = full)
+    #[serde(default)]
+    pub health: Option<f32>,
+    /// Initial food level (0-20)
+    #[serde(default)]
+    pub food: Option<u8>,
+    /// Status effects applied before the first tick
+    #[serde(default)]
+    pub effects: Vec<StatusEffect>,
+}
+
+/// Player game mode
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameMode {
+    #[default]
+    Survival,
+    Creative,
+    Adventure,
+    Spectator,
+}
+
+impl std::fmt::Display for GameMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            GameMode::Survival => "survival",
+            GameMode::Creative => "creative",
+            GameMode::Adventure => "adventure",
+            GameMode::Spectator => "spectator",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A status effect instance, e.g. `{"id": "minecraft:haste", "amplifier": 1}`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusEffect {
+    /// Effect identifier, e.g., "minecraft:haste"
+    pub id: String,
+    /// Effect level minus one (0 = level I)
+    #[serde(default)]
+    pub amplifier: u8,
+    /// Remaining duration in ticks, infinite if not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u32>,
 }
 
 fn default_selected_hotbar() -> u8 {
@@ -97,13 +451,23 @@ fn default_selected_hotbar() -> u8 {
 }
 
 /// An item that can be held or placed in a slot.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Deserializes like [`Block`]: besides `id` and `count`, every other key is an item
+/// component, either flat or nested under `components`:
+/// - `"minecraft:damage": 10`
+/// - `"minecraft:custom_name": "Bob"`
+/// - `"minecraft:enchantments": {"minecraft:silk_touch": 1}`
+///
+/// Component keys and enchantment ids may omit the `minecraft:` namespace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Item {
     /// Item identifier, e.g., "minecraft:honeycomb"
     pub id: String,
     /// Stack count (default 1)
-    #[serde(default = "default_count")]
     pub count: u8,
+    /// Item components, e.g., {"minecraft:damage": 10}
+    #[serde(flatten, skip_serializing_if = "FxHashMap::is_empty")]
+    pub components: FxHashMap<String, Nbt>,
 }
 
 impl Item {
@@ -113,15 +477,12 @@ impl Item {
         if id.starts_with("empty") {
             return Item::empty();
         }
-        Self { id, count: 1 }
+        Self::with_count(id, 1)
     }
 
     /// Create an empty item (air with count 0).
     pub fn empty() -> Self {
-        Self {
-            id: "minecraft:air".to_string(),
-            count: 0,
-        }
+        Self::with_count("minecraft:air", 0)
     }
 
     /// Create an item with a specific count.
@@ -129,14 +490,119 @@ impl Item {
         Self {
             id: id.into(),
             count,
+            components: FxHashMap::default(),
         }
     }
+
+    /// Convert an item compound like `{"id": "minecraft:stone", "count": 3}` into an item.
+    pub fn from_nbt(nbt: &Nbt) -> Option<Self> {
+        let value = serde_json::to_value(nbt).ok()?;
+        serde_json::from_value(value).ok()
+    }
+
+    /// Add an item component, e.g. `("minecraft:damage", Nbt::Int(10))`.
+    pub fn with_component(mut self, key: impl Into<String>, value: Nbt) -> Self {
+        self.components.insert(key.into(), value);
+        self
+    }
+
+    /// Get an item component, accepting keys with or without the `minecraft:` namespace.
+    pub fn component(&self, key: &str) -> Option<&Nbt> {
+        let key = key.strip_prefix("minecraft:").unwrap_or(key);
+        self.components
+            .iter()
+            .find(|(k, _)| k.strip_prefix("minecraft:").unwrap_or(k) == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Check if this item represents an empty slot.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0 || self.id == "minecraft:air" || self.id == "air"
+    }
+
+    /// Generate a `/give`-style string like `minecraft:stone 3`, or `empty` for an empty slot.
+    ///
+    /// Components are listed in key order: `minecraft:diamond_pickaxe[minecraft:damage=10]`.
+    pub fn to_command(&self) -> String {
+        if self.is_empty() {
+            return "empty".to_string();
+        }
+        let mut command = self.id.clone();
+        if !self.components.is_empty() {
+            let mut components: Vec<String> = self
+                .components
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect();
+            components.sort();
+            command.push_str(&format!("[{}]", components.join(",")));
+        }
+        if self.count != 1 {
+            command.push_str(&format!(" {}", self.count));
+        }
+        command
+    }
+}
+
+impl<'de> Deserialize<'de> for Item {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ItemVisitor;
+
+        impl<'de> Visitor<'de> for ItemVisitor {
+            type Value = Item;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter
+                    .write_str("an item object with 'id' field and optional count and components")
+            }
+
+            fn visit_map<M>(self, mut map: M) -> Result<Item, M::Error>
+            where
+                M: MapAccess<'de>,
+            {
+                let mut id: Option<String> = None;
+                let mut count = default_count();
+                let mut components = FxHashMap::default();
+
+                while let Some(key) = map.next_key::<String>()? {
+                    if key == "id" {
+                        id = Some(map.next_value()?);
+                    } else if key == "count" {
+                        count = map.next_value()?;
+                    } else if key == "components" {
+                        // Handle nested components object
+                        let nested: FxHashMap<String, Nbt> = map.next_value()?;
+                        components.extend(nested);
+                    } else {
+                        // Handle flat components
+                        components.insert(key, map.next_value()?);
+                    }
+                }
+
+                let id = id.ok_or_else(|| serde::de::Error::missing_field("id"))?;
+                Ok(Item {
+                    id,
+                    count,
+                    components,
+                })
+            }
+        }
+
+        deserializer.deserialize_map(ItemVisitor)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimelineEntry {
     #[serde(rename = "at")]
     pub at: TickSpec,
+    /// Named player performing a player action (from `setup.players`),
+    /// defaults to the implicit player
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub player: Option<String>,
     #[serde(flatten)]
     pub action_type: ActionType,
 }
@@ -165,7 +631,7 @@ impl TickSpec {
 /// - `"facing": "north"` → `"facing": "north"`
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Block {
-    /// Block identifier, e.g., "minecraft:stone"
+    /// Block identifier, e.g., "minecraft:stone", or a block tag like "#minecraft:logs" in assertions
     pub id: String,
     /// Block state properties, e.g., {"powered": "true", "facing": "north"}
     #[serde(flatten, skip_serializing_if = "FxHashMap::is_empty")]
@@ -194,6 +660,23 @@ impl Block {
         self.id == "minecraft:air" || self.id == "air"
     }
 
+    /// Check if this block refers to a block tag, e.g. `#minecraft:logs`.
+    pub fn is_tag(&self) -> bool {
+        self.id.starts_with('#')
+    }
+
+    /// Get the namespaced tag name (without `#`) if this block is a tag reference.
+    ///
+    /// Tags without a namespace default to `minecraft:`, so `#logs` → `minecraft:logs`.
+    pub fn tag(&self) -> Option<String> {
+        let tag = self.id.strip_prefix('#')?;
+        if tag.contains(':') {
+            Some(tag.to_string())
+        } else {
+            Some(format!("minecraft:{}", tag))
+        }
+    }
+
     /// Generate a Minecraft command string like `minecraft:lever[powered=false,face=floor]`.
     pub fn to_command(&self) -> String {
         if self.properties.is_empty() {
@@ -267,6 +750,55 @@ fn json_value_to_string(value: &serde_json::Value) -> String {
         _ => value.to_string(),
     }
 }
+/// Numeric assertion value: an exact number (`15`) or an inclusive range (`{"min": 1, "max": 14}`)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NumberMatcher {
+    Exact(f64),
+    Range {
+        #[serde(default)]
+        min: Option<f64>,
+        #[serde(default)]
+        max: Option<f64>,
+    },
+}
+
+impl NumberMatcher {
+    /// Check if a value satisfies this matcher.
+    pub fn matches(&self, value: f64) -> bool {
+        match self {
+            NumberMatcher::Exact(expected) => value == *expected,
+            NumberMatcher::Range { min, max } => {
+                min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for NumberMatcher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NumberMatcher::Exact(value) => write!(f, "{}", value),
+            NumberMatcher::Range {
+                min: Some(min),
+                max: Some(max),
+            } => write!(f, "{}..={}", min, max),
+            NumberMatcher::Range {
+                min: Some(min),
+                max: None,
+            } => write!(f, ">= {}", min),
+            NumberMatcher::Range {
+                min: None,
+                max: Some(max),
+            } => write!(f, "<= {}", max),
+            NumberMatcher::Range {
+                min: None,
+                max: None,
+            } => write!(f, "any"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum BlockFace {
@@ -278,6 +810,51 @@ pub enum BlockFace {
     West,   // -X
 }
 
+impl BlockFace {
+    /// Center of this face relative to the block's minimum corner, e.g. `[0.5, 1.0, 0.5]` for top.
+    pub fn center(&self) -> [f64; 3] {
+        match self {
+            BlockFace::Top => [0.5, 1.0, 0.5],
+            BlockFace::Bottom => [0.5, 0.0, 0.5],
+            BlockFace::North => [0.5, 0.5, 0.0],
+            BlockFace::South => [0.5, 0.5, 1.0],
+            BlockFace::East => [1.0, 0.5, 0.5],
+            BlockFace::West => [0.0, 0.5, 0.5],
+        }
+    }
+}
+
+/// Hand used for an interaction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hand {
+    #[default]
+    Main,
+    Off,
+}
+
+/// How block actions change blocks
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaceMode {
+    /// Update neighbors and shapes like a normal block change
+    #[default]
+    Update,
+    /// Change the block without neighbor or shape updates
+    Silent,
+}
+
+/// How a player breaks a block
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakMode {
+    /// Break the block in the same tick
+    #[default]
+    Instant,
+    /// Mine over several ticks like a survival player; duration depends on tool and block
+    Survival,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "do", rename_all = "snake_case")]
 pub enum ActionType {
@@ -285,16 +862,36 @@ pub enum ActionType {
     Place {
         pos: [i32; 3],
         block: Block,
+        #[serde(default)]
+        mode: PlaceMode,
     },
     PlaceEach {
         blocks: Vec<BlockPlacement>,
+        #[serde(default)]
+        mode: PlaceMode,
     },
     Fill {
         region: [[i32; 3]; 2],
         with: Block,
+        #[serde(default)]
+        mode: PlaceMode,
     },
     Remove {
         pos: [i32; 3],
+        #[serde(default)]
+        mode: PlaceMode,
+    },
+    /// Merge data into the block entity at a position (sign text, chest contents, ...)
+    SetBlockData {
+        pos: [i32; 3],
+        data: Nbt,
+    },
+
+    /// Replace the contents of the container at a position (unlisted slots are cleared)
+    SetContainer {
+        pos: [i32; 3],
+        #[serde(default)]
+        items: Vec<ContainerSlot>,
     },
 
     // Assertion actions
@@ -302,6 +899,66 @@ pub enum ActionType {
         checks: Vec<BlockCheck>,
     },
 
+    /// Check the redstone power at a position
+    ///
+    /// With `face`, checks the signal received through that face, or with `strong`
+    /// the strong signal emitted through it. Otherwise the highest received signal.
+    AssertPower {
+        pos: [i32; 3],
+        #[serde(default)]
+        face: Option<BlockFace>,
+        #[serde(default)]
+        strong: bool,
+        power: NumberMatcher,
+    },
+
+    /// Place a fluid, waterlogging the block at `pos` if it can hold the fluid
+    PlaceFluid {
+        pos: [i32; 3],
+        /// Fluid id, e.g. "minecraft:water" or "minecraft:lava"
+        fluid: String,
+        /// Flowing fluid amount (1-7), places a source if not set
+        #[serde(default)]
+        amount: Option<u8>,
+        /// Place falling fluid (amount 8) instead of a source, cannot be combined with `amount`
+        #[serde(default)]
+        falling: bool,
+    },
+
+    /// Check the fluid at a position, including fluid in waterlogged blocks
+    AssertFluid {
+        pos: [i32; 3],
+        /// Expected fluid id, "minecraft:empty" expects no fluid
+        fluid: String,
+        /// Expected amount (1-8, sources and falling fluid have 8)
+        #[serde(default)]
+        amount: Option<NumberMatcher>,
+        #[serde(default)]
+        falling: Option<bool>,
+        #[serde(default)]
+        source: Option<bool>,
+    },
+
+    /// Check the block light and/or sky light level at a position
+    AssertLight {
+        pos: [i32; 3],
+        #[serde(default)]
+        block: Option<NumberMatcher>,
+        #[serde(default)]
+        sky: Option<NumberMatcher>,
+    },
+
+    /// Check the contents of the container at a position
+    AssertContainer {
+        pos: [i32; 3],
+        /// Expected item per slot, `{"id": "minecraft:air"}` expects an empty slot
+        #[serde(default)]
+        slots: Vec<ContainerSlot>,
+        /// Minimum total count of each item across all slots
+        #[serde(default)]
+        contains: Vec<Item>,
+    },
+
     // Player actions (for item interactions)
     /// Use an item on a block face (e.g., honeycomb on copper, axe on log)
     UseItemOn {
@@ -310,6 +967,67 @@ pub enum ActionType {
         /// Item to use (for simple mode). If not specified, uses player's active item.
         #[serde(default)]
         item: Option<String>,
+        /// Click position relative to the block (0.0-1.0 per axis), defaults to the face center
+        #[serde(default)]
+        hit: Option<[f64; 3]>,
+        /// Hand holding the item
+        #[serde(default)]
+        hand: Hand,
+        /// Whether the player is sneaking (e.g., to place against interactable blocks)
+        #[serde(default)]
+        sneaking: bool,
+    },
+
+    /// Use an item without targeting a block (e.g., eat food, throw an ender pearl, fire a bow)
+    UseItem {
+        #[serde(default)]
+        hand: Hand,
+        /// Ticks to keep using the item before releasing it (0 = single click)
+        #[serde(default)]
+        ticks: u32,
+        /// Item to use (for simple mode). If not specified, uses the item in `hand`.
+        #[serde(default)]
+        item: Option<String>,
+    },
+
+    /// Right click the entity closest to `pos` (e.g., shears on a sheep, bucket on a cow)
+    UseItemOnEntity {
+        /// Only target entities of this type
+        #[serde(default)]
+        entity: Option<String>,
+        pos: [f64; 3],
+        #[serde(default = "default_tolerance")]
+        tolerance: f64,
+        #[serde(default)]
+        hand: Hand,
+        /// Item to use (for simple mode). If not specified, uses the item in `hand`.
+        #[serde(default)]
+        item: Option<String>,
+    },
+
+    /// Attack the entity closest to `pos`
+    AttackEntity {
+        /// Only target entities of this type
+        #[serde(default)]
+        entity: Option<String>,
+        pos: [f64; 3],
+        #[serde(default = "default_tolerance")]
+        tolerance: f64,
+        /// Weapon to use (for simple mode). If not specified, uses player's active item.
+        #[serde(default)]
+        item: Option<String>,
+    },
+
+    /// Break a block as the player with the active item (e.g., pickaxe on stone)
+    BreakBlock {
+        pos: [i32; 3],
+        #[serde(default = "default_break_face")]
+        face: BlockFace,
+        #[serde(default)]
+        mode: BreakMode,
+        /// Tool to use (for simple mode). If not specified, uses player's active item.
+        #[serde(default)]
+        item: Option<String>,
     },
 
     /// Set an item in a player slot
@@ -319,24 +1037,341 @@ pub enum ActionType {
         item: Option<String>,
         #[serde(default = "default_count")]
         count: u8,
+        /// Item components of the new item
+        #[serde(default)]
+        components: FxHashMap<String, Nbt>,
     },
 
     /// Select which hotbar slot is active (1-9)
     SelectHotbar {
         slot: u8,
     },
+
+    /// Check the item in a player slot (omit `item` to expect an empty slot)
+    AssertSlot {
+        slot: PlayerSlot,
+        #[serde(default)]
+        item: Option<String>,
+        #[serde(default = "default_count")]
+        count: u8,
+        /// Expected item components (partial match, other components are ignored)
+        #[serde(default)]
+        components: FxHashMap<String, Nbt>,
+    },
+
+    /// Check which hotbar slot is active (1-9)
+    AssertSelectedHotbar {
+        slot: u8,
+    },
+
+    // Entity actions
+    /// Spawn an entity at an exact position, e.g. `[1.5, 1.0, 1.5]` for the center of a block
+    SpawnEntity {
+        entity: String,
+        pos: [f64; 3],
+        #[serde(default)]
+        data: Option<Nbt>,
+    },
+
+    /// Remove all entities in a region, optionally only of one type
+    KillEntities {
+        region: [[i32; 3]; 2],
+        #[serde(default)]
+        entity: Option<String>,
+    },
+
+    /// Check the items dropped as item entities in a region (compared as a multiset by id)
+    AssertDrops {
+        region: [[i32; 3]; 2],
+        /// Expected drops, entries with the same id are summed
+        #[serde(default)]
+        items: Vec<Item>,
+        /// Only consider item entities spawned at or after this tick
+        #[serde(default)]
+        since: Option<u32>,
+    },
+
+    /// Check the entities in a region
+    AssertEntities {
+        region: [[i32; 3]; 2],
+        /// Only consider entities of this type
+        #[serde(default)]
+        entity: Option<String>,
+        /// Only consider entities within `tolerance` blocks of this position
+        #[serde(default)]
+        pos: Option<[f64; 3]>,
+        #[serde(default = "default_tolerance")]
+        tolerance: f64,
+        /// Only consider entities whose data contains this (partial match)
+        #[serde(default)]
+        data: Option<Nbt>,
+        /// Expected number of matching entities (default: at least one)
+        #[serde(default)]
+        count: Option<usize>,
+    },
+
+    // World actions
+    /// Set the day time in ticks (0 = sunrise, 6000 = noon, 13000 = night)
+    SetTime {
+        time: u64,
+    },
+
+    /// Change the weather
+    SetWeather {
+        weather: Weather,
+    },
+
+    /// Change a game rule, e.g. `{"rule": "doDaylightCycle", "value": false}`
+    SetGameRule {
+        rule: String,
+        value: GameRuleValue,
+    },
+
+    /// Schedule a block or fluid tick, e.g. to trigger an observer or repeater without an update
+    ScheduleTick {
+        pos: [i32; 3],
+        /// Game ticks until the scheduled tick runs
+        delay: u32,
+        /// Vanilla tick priority, -3 (extremely high) to 3 (extremely low)
+        #[serde(default)]
+        priority: i32,
+        /// Block or fluid to tick, defaults to the block currently at `pos`
+        #[serde(default)]
+        target: Option<String>,
+    },
+
+    /// Check the pending scheduled ticks at a position
+    AssertScheduled {
+        pos: [i32; 3],
+        /// Only count ticks for this block or fluid
+        #[serde(default)]
+        target: Option<String>,
+        #[serde(default)]
+        delay: Option<NumberMatcher>,
+        #[serde(default)]
+        priority: Option<i32>,
+        /// Expected number of matching ticks (default: at least one, 0 = none pending)
+        #[serde(default)]
+        count: Option<usize>,
+    },
+
+    /// Check the events captured in a region, e.g. neighbor updates or sounds
+    AssertEvents {
+        region: [[i32; 3]; 2],
+        /// Only consider events at or after this tick (default: test start)
+        #[serde(default)]
+        since: Option<u32>,
+        /// Only consider events at or before this tick (default: the assertion tick)
+        #[serde(default)]
+        until: Option<u32>,
+        /// Events that must have happened
+        #[serde(default)]
+        events: Vec<EventMatcher>,
+        /// Whether `events` must happen in the listed order (other events may occur in between)
+        #[serde(default)]
+        ordered: bool,
+        /// Events that must not have happened
+        #[serde(default)]
+        absent: Vec<EventMatcher>,
+    },
+
+    /// Change the biome in a region, restored after the test
+    SetBiome {
+        region: [[i32; 3]; 2],
+        /// Biome id, e.g. "minecraft:snowy_plains"
+        biome: String,
+    },
+
+    /// Check the biome at a position
+    AssertBiome {
+        pos: [i32; 3],
+        biome: String,
+    },
+
+    /// Force a random tick on a block (crop growth, copper oxidation, leaf decay)
+    RandomTick {
+        pos: [i32; 3],
+    },
+
+    // Command actions
+    /// Run a server command, failing the test if it does not succeed.
+    /// `~` coordinates are relative to the test origin, e.g. `setblock ~1 ~ ~ minecraft:stone`,
+    /// absolute coordinates stay absolute.
+    Command {
+        command: String,
+    },
+
+    /// Run a server command and check its result (default: must succeed)
+    AssertCommand {
+        command: String,
+        /// Expected success, not checked if only `output` is given
+        #[serde(default)]
+        success: Option<bool>,
+        /// Text the command output must contain
+        #[serde(default)]
+        output: Option<String>,
+    },
+
+    /// Teleport the player to an exact position and rotation
+    Teleport {
+        pos: [f64; 3],
+        #[serde(default)]
+        yaw: f32,
+        #[serde(default)]
+        pitch: f32,
+    },
+
+    /// Move the player by a delta with collisions
+    Move {
+        by: [f64; 3],
+    },
+
+    /// Make the player jump
+    Jump,
+
+    /// Check the player position, ground state, game mode, health, food and effects
+    AssertPlayer {
+        #[serde(default)]
+        pos: Option<[f64; 3]>,
+        #[serde(default = "default_tolerance")]
+        tolerance: f64,
+        #[serde(default)]
+        on_ground: Option<bool>,
+        #[serde(default)]
+        game_mode: Option<GameMode>,
+        #[serde(default)]
+        health: Option<NumberMatcher>,
+        #[serde(default)]
+        food: Option<NumberMatcher>,
+        /// Effects the player must have (id and amplifier, duration is ignored)
+        #[serde(default)]
+        effects: Vec<StatusEffect>,
+    },
+
+    /// Replace the whole player inventory (unlisted slots are cleared)
+    SetInventory {
+        #[serde(default)]
+        items: HashMap<PlayerSlot, Item>,
+    },
+
+    /// Clear every player slot, including armor, off-hand and cursor
+    ClearInventory,
 }
 
 fn default_count() -> u8 {
     1
 }
 
+fn default_break_face() -> BlockFace {
+    BlockFace::Top
+}
+
+fn default_tolerance() -> f64 {
+    0.5
+}
+
+impl ActionType {
+    /// Whether this action is performed by (or inspects) a player
+    pub fn is_player_action(&self) -> bool {
+        match self {
+            ActionType::UseItemOn { .. }
+            | ActionType::UseItem { .. }
+            | ActionType::UseItemOnEntity { .. }
+            | ActionType::AttackEntity { .. }
+            | ActionType::BreakBlock { .. }
+            | ActionType::SetSlot { .. }
+            | ActionType::SelectHotbar { .. }
+            | ActionType::AssertSlot { .. }
+            | ActionType::AssertSelectedHotbar { .. }
+            | ActionType::SetInventory { .. }
+            | ActionType::ClearInventory
+            | ActionType::Teleport { .. }
+            | ActionType::Move { .. }
+            | ActionType::Jump
+            | ActionType::AssertPlayer { .. } => true,
+            ActionType::Place { .. }
+            | ActionType::PlaceEach { .. }
+            | ActionType::Fill { .. }
+            | ActionType::Remove { .. }
+            | ActionType::SetBlockData { .. }
+            | ActionType::SetContainer { .. }
+            | ActionType::Assert { .. }
+            | ActionType::AssertPower { .. }
+            | ActionType::AssertContainer { .. }
+            | ActionType::SpawnEntity { .. }
+            | ActionType::KillEntities { .. }
+            | ActionType::AssertDrops { .. }
+            | ActionType::AssertEntities { .. }
+            | ActionType::Command { .. }
+            | ActionType::AssertCommand { .. }
+            | ActionType::SetTime { .. }
+            | ActionType::SetWeather { .. }
+            | ActionType::SetGameRule { .. }
+            | ActionType::RandomTick { .. }
+            | ActionType::ScheduleTick { .. }
+            | ActionType::AssertScheduled { .. }
+            | ActionType::AssertEvents { .. }
+            | ActionType::AssertLight { .. }
+            | ActionType::PlaceFluid { .. }
+            | ActionType::AssertFluid { .. }
+            | ActionType::SetBiome { .. }
+            | ActionType::AssertBiome { .. } => false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockPlacement {
     pub pos: [i32; 3],
     pub block: Block,
 }
 
+/// Kind of a captured world event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    /// Neighbor update received by a block
+    BlockUpdate,
+    /// Shape (post-placement) update received by a block
+    ShapeUpdate,
+    /// Vanilla game event, e.g. "minecraft:block_activate"
+    GameEvent,
+    /// Sound played, e.g. "minecraft:block.note_block.harp"
+    Sound,
+}
+
+impl std::fmt::Display for EventKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            EventKind::BlockUpdate => "block_update",
+            EventKind::ShapeUpdate => "shape_update",
+            EventKind::GameEvent => "game_event",
+            EventKind::Sound => "sound",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Filter for captured events, unset fields match anything
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventMatcher {
+    pub kind: EventKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pos: Option<[i32; 3]>,
+    /// Updated block, game event or sound id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+/// An item in a numbered container slot, e.g. `{"slot": 0, "id": "minecraft:coal", "count": 8}`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContainerSlot {
+    /// Slot index, starting at 0
+    pub slot: usize,
+    #[serde(flatten)]
+    pub item: Item,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum BlockSpec {
@@ -357,6 +1392,9 @@ impl BlockSpec {
 pub struct BlockCheck {
     pub pos: [i32; 3],
     pub is: BlockSpec,
+    /// Expected block entity data, partially matched against the actual data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Nbt>,
 }
 
 impl TestSpec {
@@ -452,13 +1490,74 @@ impl TestSpec {
             }
         }
 
+        // Validate the world build height can hold the test
+        let world = &setup.world;
+        if let (Some(min_y), Some(max_y)) = (world.min_y, world.max_y)
+            && min_y > max_y
+        {
+            anyhow::bail!(
+                "Test '{}': World min_y {} is above max_y {}",
+                self.name,
+                min_y,
+                max_y
+            );
+        }
+        // Corners are only ordered when validating with cleanup
+        let low = region[0][1].min(region[1][1]);
+        let high = region[0][1].max(region[1][1]);
+        if world.min_y.is_some_and(|min_y| low < min_y)
+            || world.max_y.is_some_and(|max_y| high > max_y)
+        {
+            anyhow::bail!(
+                "Test '{}': Cleanup region Y range {}..={} is outside the world build height",
+                self.name,
+                low,
+                high
+            );
+        }
+
         // Validate all test coordinates are within cleanup region
+        let player_configs = setup.player.iter().chain(setup.players.values());
+        for pos in player_configs.filter_map(|p| p.position) {
+            self.validate_position(pos.map(|c| c.floor() as i32), &region)?;
+        }
         for entry in &self.timeline {
+            if let Some(name) = &entry.player {
+                if !entry.action_type.is_player_action() {
+                    anyhow::bail!(
+                        "Test '{}': 'player' is only allowed on player actions",
+                        self.name
+                    );
+                }
+                if !setup.players.contains_key(name) {
+                    anyhow::bail!(
+                        "Test '{}': Unknown player '{}', declare it in setup.players",
+                        self.name,
+                        name
+                    );
+                }
+            }
+            let placed: Vec<&Block> = match &entry.action_type {
+                ActionType::Place { block, .. } | ActionType::Fill { with: block, .. } => {
+                    vec![block]
+                }
+                ActionType::PlaceEach { blocks, .. } => {
+                    blocks.iter().map(|placement| &placement.block).collect()
+                }
+                _ => Vec::new(),
+            };
+            if let Some(block) = placed.into_iter().find(|block| block.is_tag()) {
+                anyhow::bail!(
+                    "Test '{}': Cannot place block tag '{}', block tags are only allowed in assertions",
+                    self.name,
+                    block.id
+                );
+            }
             match &entry.action_type {
                 ActionType::Place { pos, .. } => {
                     self.validate_position(*pos, &region)?;
                 }
-                ActionType::PlaceEach { blocks } => {
+                ActionType::PlaceEach { blocks, .. } => {
                     for block in blocks {
                         self.validate_position(block.pos, &region)?;
                     }
@@ -466,11 +1565,60 @@ impl TestSpec {
                 ActionType::Fill {
                     region: fill_region,
                     ..
+                }
+                | ActionType::KillEntities {
+                    region: fill_region,
+                    ..
+                }
+                | ActionType::AssertEntities {
+                    region: fill_region,
+                    ..
+                }
+                | ActionType::AssertDrops {
+                    region: fill_region,
+                    ..
+                }
+                | ActionType::SetBiome {
+                    region: fill_region,
+                    ..
                 } => {
                     self.validate_position(fill_region[0], &region)?;
                     self.validate_position(fill_region[1], &region)?;
                 }
-                ActionType::Remove { pos } => {
+                ActionType::AssertEvents {
+                    region: events_region,
+                    since,
+                    until,
+                    ..
+                } => {
+                    self.validate_position(events_region[0], &region)?;
+                    self.validate_position(events_region[1], &region)?;
+                    if let (Some(since), Some(until)) = (since, until)
+                        && since > until
+                    {
+                        anyhow::bail!(
+                            "Test '{}': assert_events 'since' {} is after 'until' {}",
+                            self.name,
+                            since,
+                            until
+                        );
+                    }
+                }
+                ActionType::SpawnEntity { pos, .. }
+                | ActionType::Teleport { pos, .. }
+                | ActionType::UseItemOnEntity { pos, .. }
+                | ActionType::AttackEntity { pos, .. } => {
+                    let block_pos = pos.map(|c| c.floor() as i32);
+                    self.validate_position(block_pos, &region)?;
+                }
+                ActionType::Remove { pos, .. }
+                | ActionType::SetBlockData { pos, .. }
+                | ActionType::SetContainer { pos, .. }
+                | ActionType::AssertContainer { pos, .. }
+                | ActionType::RandomTick { pos }
+                | ActionType::AssertScheduled { pos, .. }
+                | ActionType::AssertFluid { pos, .. }
+                | ActionType::AssertBiome { pos, .. } => {
                     self.validate_position(*pos, &region)?;
                 }
                 ActionType::Assert { checks } => {
@@ -478,11 +1626,99 @@ impl TestSpec {
                         self.validate_position(check.pos, &region)?;
                     }
                 }
-                ActionType::UseItemOn { pos, .. } => {
+                ActionType::UseItemOn { pos, hit, .. } => {
+                    self.validate_position(*pos, &region)?;
+                    if let Some(hit) = hit
+                        && hit.iter().any(|c| !(0.0..=1.0).contains(c))
+                    {
+                        anyhow::bail!(
+                            "Test '{}': Hit position [{},{},{}] must be within 0.0-1.0 relative to the block",
+                            self.name,
+                            hit[0],
+                            hit[1],
+                            hit[2]
+                        );
+                    }
+                }
+                ActionType::BreakBlock { pos, .. } => {
+                    self.validate_position(*pos, &region)?;
+                }
+                ActionType::PlaceFluid {
+                    pos,
+                    amount,
+                    falling,
+                    ..
+                } => {
+                    self.validate_position(*pos, &region)?;
+                    if amount.is_some() && *falling {
+                        anyhow::bail!(
+                            "Test '{}': Falling fluid at {:?} always has amount 8, remove 'amount'",
+                            self.name,
+                            pos
+                        );
+                    }
+                    if let Some(amount) = amount
+                        && !(1..=7).contains(amount)
+                    {
+                        anyhow::bail!(
+                            "Test '{}': Flowing fluid amount {} must be within 1-7",
+                            self.name,
+                            amount
+                        );
+                    }
+                }
+                ActionType::AssertPower {
+                    pos, face, strong, ..
+                } => {
+                    self.validate_position(*pos, &region)?;
+                    if *strong && face.is_none() {
+                        anyhow::bail!(
+                            "Test '{}': assert_power at {:?} needs a 'face' to check the strong signal",
+                            self.name,
+                            pos
+                        );
+                    }
+                }
+                ActionType::AssertLight { pos, block, sky } => {
+                    self.validate_position(*pos, &region)?;
+                    if block.is_none() && sky.is_none() {
+                        anyhow::bail!(
+                            "Test '{}': assert_light at {:?} needs 'block' or 'sky'",
+                            self.name,
+                            pos
+                        );
+                    }
+                }
+                ActionType::ScheduleTick { pos, priority, .. } => {
                     self.validate_position(*pos, &region)?;
+                    if !(-3..=3).contains(priority) {
+                        anyhow::bail!(
+                            "Test '{}': Tick priority {} must be within -3..=3",
+                            self.name,
+                            priority
+                        );
+                    }
+                }
+                ActionType::Command { command } | ActionType::AssertCommand { command, .. } => {
+                    if command.trim_start_matches('/').trim().is_empty() {
+                        anyhow::bail!("Test '{}': Command must not be empty", self.name);
+                    }
                 }
-                // SetSlot and SelectHotbar don't have positions to validate
-                ActionType::SetSlot { .. } | ActionType::SelectHotbar { .. } => {}
+                // Player slot actions don't have positions to validate
+                ActionType::SetSlot { .. }
+                | ActionType::SelectHotbar { .. }
+                | ActionType::AssertSlot { .. }
+                | ActionType::AssertSelectedHotbar { .. }
+                | ActionType::SetInventory { .. }
+                | ActionType::ClearInventory
+                | ActionType::UseItem { .. }
+                | ActionType::Move { .. }
+                | ActionType::Jump
+                | ActionType::AssertPlayer { .. } => {}
+                // World settings apply to the whole world
+                ActionType::SetTime { .. }
+                | ActionType::SetWeather { .. }
+                | ActionType::SetGameRule { .. } => {}
             }
         }
 
@@ -521,6 +1757,7 @@ impl TestSpec {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::spec;
 
     #[test]
     fn redstone_lever_with_two_properties_command_string() {
@@ -781,4 +2018,812 @@ mod tests {
         let stone = Block::new("minecraft:stone");
         assert!(!stone.is_air());
     }
+
+    #[test]
+    fn test_parse_block_data_check() {
+        let json = r#"{
+            "pos": [0, 0, 0],
+            "is": {"id": "minecraft:chest"},
+            "data": {"Items": [{"id": "minecraft:stone", "count": 3}]}
+        }"#;
+
+        let check: BlockCheck = serde_json::from_str(json).unwrap();
+        let data = check.data.unwrap();
+        assert!(matches!(data.get("Items"), Some(Nbt::List(items)) if items.len() == 1));
+
+        let json = r#"{"pos": [0, 0, 0], "is": {"id": "minecraft:stone"}}"#;
+        let check: BlockCheck = serde_json::from_str(json).unwrap();
+        assert!(check.data.is_none());
+    }
+
+    #[test]
+    fn test_parse_assert_container() {
+        let json = r#"{
+            "at": 4,
+            "do": "assert_container",
+            "pos": [0, 1, 0],
+            "slots": [
+                {"slot": 0, "id": "minecraft:iron_ingot", "count": 2},
+                {"slot": 1, "id": "minecraft:air"}
+            ],
+            "contains": [{"id": "minecraft:coal", "count": 7}]
+        }"#;
+
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        match entry.action_type {
+            ActionType::AssertContainer {
+                pos,
+                slots,
+                contains,
+            } => {
+                assert_eq!(pos, [0, 1, 0]);
+                assert_eq!(slots[0].slot, 0);
+                assert_eq!(slots[0].item, Item::with_count("minecraft:iron_ingot", 2));
+                assert_eq!(slots[1].slot, 1);
+                assert!(slots[1].item.is_empty());
+                assert_eq!(contains, vec![Item::with_count("minecraft:coal", 7)]);
+            }
+            other => panic!("unexpected action {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_assert_slot() {
+        let json = r#"{"at": 2, "do": "assert_slot", "slot": "hotbar1", "item": "minecraft:water_bucket"}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::AssertSlot { slot: PlayerSlot::Hotbar1, item: Some(ref id), count: 1, .. }
+                if id == "minecraft:water_bucket"
+        ));
+
+        let json = r#"{"at": 2, "do": "assert_slot", "slot": "off_hand"}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::AssertSlot {
+                slot: PlayerSlot::OffHand,
+                item: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_player_slot_names() {
+        let slots: Vec<PlayerSlot> =
+            serde_json::from_str(r#"["hotbar1", "hotbar_9", "inventory27", "off_hand", "cursor"]"#)
+                .unwrap();
+        assert_eq!(
+            slots,
+            vec![
+                PlayerSlot::Hotbar1,
+                PlayerSlot::Hotbar9,
+                PlayerSlot::Inventory27,
+                PlayerSlot::OffHand,
+                PlayerSlot::Cursor
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&PlayerSlot::Inventory1).unwrap(),
+            r#""inventory1""#
+        );
+    }
+
+    #[test]
+    fn test_player_slot_numbers() {
+        assert_eq!(PlayerSlot::inventory(1), Some(PlayerSlot::Inventory1));
+        assert_eq!(PlayerSlot::inventory(27), Some(PlayerSlot::Inventory27));
+        assert_eq!(PlayerSlot::inventory(28), None);
+        assert_eq!(PlayerSlot::hotbar(0), None);
+
+        let unique: std::collections::HashSet<_> = PlayerSlot::ALL.iter().collect();
+        assert_eq!(unique.len(), PlayerSlot::ALL.len());
+    }
+
+    #[test]
+    fn test_parse_set_inventory() {
+        let json = r#"{
+            "at": 0,
+            "do": "set_inventory",
+            "items": {"inventory5": {"id": "minecraft:oak_planks", "count": 4}}
+        }"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        match entry.action_type {
+            ActionType::SetInventory { items } => {
+                assert_eq!(
+                    items[&PlayerSlot::Inventory5],
+                    Item::with_count("minecraft:oak_planks", 4)
+                );
+            }
+            other => panic!("unexpected action {:?}", other),
+        }
+
+        let json = r#"{"at": 0, "do": "clear_inventory"}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(entry.action_type, ActionType::ClearInventory));
+    }
+
+    #[test]
+    fn test_parse_item_components() {
+        let json = r#"{
+            "id": "minecraft:diamond_pickaxe",
+            "minecraft:damage": 10,
+            "components": {"minecraft:enchantments": {"minecraft:silk_touch": 1}}
+        }"#;
+
+        let item: Item = serde_json::from_str(json).unwrap();
+        assert_eq!(item.id, "minecraft:diamond_pickaxe");
+        assert_eq!(item.count, 1);
+        assert_eq!(item.component("damage"), Some(&Nbt::Int(10)));
+        assert!(
+            item.component("minecraft:enchantments")
+                .is_some_and(|e| e.get("minecraft:silk_touch") == Some(&Nbt::Int(1)))
+        );
+        assert_eq!(
+            item.to_command(),
+            r#"minecraft:diamond_pickaxe[minecraft:damage=10,minecraft:enchantments={"minecraft:silk_touch":1}]"#
+        );
+    }
+
+    #[test]
+    fn test_item_components_roundtrip() {
+        let item = Item::with_count("minecraft:stone", 3)
+            .with_component("minecraft:custom_name", Nbt::String("Bob".to_string()));
+        let json = serde_json::to_string(&item).unwrap();
+        let parsed: Item = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, item);
+    }
+
+    #[test]
+    fn test_parse_entity_actions() {
+        let json = r#"{"at": 0, "do": "spawn_entity", "entity": "minecraft:minecart", "pos": [1.5, 1, 0.5]}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::SpawnEntity { ref entity, pos, data: None }
+                if entity == "minecraft:minecart" && pos == [1.5, 1.0, 0.5]
+        ));
+
+        let json = r#"{
+            "at": 20,
+            "do": "assert_entities",
+            "region": [[0, 0, 0], [4, 2, 4]],
+            "entity": "minecraft:minecart",
+            "pos": [3.5, 1, 0.5],
+            "count": 1
+        }"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        match entry.action_type {
+            ActionType::AssertEntities {
+                tolerance, count, ..
+            } => {
+                assert_eq!(tolerance, 0.5);
+                assert_eq!(count, Some(1));
+            }
+            other => panic!("unexpected action {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_item_from_nbt() {
+        let nbt: Nbt =
+            serde_json::from_str(r#"{"id": "minecraft:cobblestone", "count": 2}"#).unwrap();
+        assert_eq!(
+            Item::from_nbt(&nbt),
+            Some(Item::with_count("minecraft:cobblestone", 2))
+        );
+        assert_eq!(Item::from_nbt(&Nbt::Int(1)), None);
+    }
+
+    #[test]
+    fn test_parse_assert_light() {
+        let json =
+            r#"{"at": 1, "do": "assert_light", "pos": [0, 1, 0], "block": 14, "sky": {"max": 0}}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::AssertLight {
+                block: Some(NumberMatcher::Exact(_)),
+                sky: Some(NumberMatcher::Range { .. }),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_fluid_actions() {
+        let json =
+            r#"{"at": 0, "do": "place_fluid", "pos": [0, 1, 0], "fluid": "minecraft:water"}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::PlaceFluid {
+                amount: None,
+                falling: false,
+                ..
+            }
+        ));
+
+        let json = r#"{"at": 5, "do": "assert_fluid", "pos": [1, 1, 0], "fluid": "water", "amount": 7, "source": false}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::AssertFluid {
+                amount: Some(NumberMatcher::Exact(_)),
+                falling: None,
+                source: Some(false),
+                ..
+            }
+        ));
+
+        let setup = r#"{"cleanup": {"region": [[0, 0, 0], [4, 4, 4]]}}"#;
+        let falling = r#"{"at": 0, "do": "place_fluid", "pos": [0, 1, 0], "fluid": "water", "falling": true}"#;
+        let flowing =
+            r#"{"at": 0, "do": "place_fluid", "pos": [0, 1, 0], "fluid": "water", "amount": 3}"#;
+        let both = r#"{"at": 0, "do": "place_fluid", "pos": [0, 1, 0], "fluid": "water", "amount": 3, "falling": true}"#;
+        spec(setup, &[falling]).validate(true).unwrap();
+        spec(setup, &[flowing]).validate(true).unwrap();
+        assert!(spec(setup, &[both]).validate(true).is_err());
+    }
+
+    #[test]
+    fn test_parse_biome_actions() {
+        let json = r#"{"at": 0, "do": "set_biome", "region": [[0, 0, 0], [3, 3, 3]], "biome": "minecraft:snowy_plains"}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::SetBiome { ref biome, .. } if biome == "minecraft:snowy_plains"
+        ));
+
+        let json = r#"{"at": 1, "do": "assert_biome", "pos": [1, 1, 1], "biome": "snowy_plains"}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::AssertBiome { pos: [1, 1, 1], .. }
+        ));
+    }
+
+    #[test]
+    fn test_parse_place_mode() {
+        let json =
+            r#"{"at": 0, "do": "place", "pos": [0, 0, 0], "block": {"id": "minecraft:rail"}}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::Place {
+                mode: PlaceMode::Update,
+                ..
+            }
+        ));
+
+        let json = r#"{"at": 0, "do": "fill", "region": [[0, 0, 0], [2, 0, 0]], "with": {"id": "minecraft:redstone_wire"}, "mode": "silent"}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::Fill {
+                mode: PlaceMode::Silent,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_number_matcher() {
+        let exact: NumberMatcher = serde_json::from_str("15").unwrap();
+        assert!(exact.matches(15.0));
+        assert!(!exact.matches(14.0));
+        assert_eq!(exact.to_string(), "15");
+
+        let range: NumberMatcher = serde_json::from_str(r#"{"min": 1, "max": 14}"#).unwrap();
+        assert!(range.matches(1.0) && range.matches(14.0));
+        assert!(!range.matches(0.0) && !range.matches(15.0));
+        assert_eq!(range.to_string(), "1..=14");
+
+        let at_least: NumberMatcher = serde_json::from_str(r#"{"min": 1}"#).unwrap();
+        assert!(at_least.matches(15.0));
+        assert!(!at_least.matches(0.0));
+        assert_eq!(at_least.to_string(), ">= 1");
+    }
+
+    #[test]
+    fn test_parse_break_block() {
+        let json = r#"{"at": 0, "do": "break_block", "pos": [0, 1, 0]}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::BreakBlock {
+                face: BlockFace::Top,
+                mode: BreakMode::Instant,
+                item: None,
+                ..
+            }
+        ));
+
+        let json = r#"{
+            "at": 0,
+            "do": "break_block",
+            "pos": [0, 1, 0],
+            "face": "north",
+            "mode": "survival",
+            "item": "minecraft:iron_pickaxe"
+        }"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::BreakBlock {
+                face: BlockFace::North,
+                mode: BreakMode::Survival,
+                item: Some(_),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_player_movement() {
+        let json = r#"{"at": 0, "do": "teleport", "pos": [0.5, 1, 0.5], "yaw": 90}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::Teleport { pos, yaw, pitch }
+                if pos == [0.5, 1.0, 0.5] && yaw == 90.0 && pitch == 0.0
+        ));
+
+        let json = r#"{"at": 5, "do": "assert_player", "pos": [0.5, 2, 0.5], "on_ground": true}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::AssertPlayer {
+                pos: Some(_),
+                tolerance,
+                on_ground: Some(true),
+                game_mode: None,
+                ..
+            } if tolerance == 0.5
+        ));
+
+        let config: PlayerConfig =
+            serde_json::from_str(r#"{"position": [2.5, 1, 2.5], "yaw": 180}"#).unwrap();
+        assert_eq!(config.position, Some([2.5, 1.0, 2.5]));
+        assert_eq!(config.yaw, 180.0);
+        assert_eq!(config.selected_hotbar, 1);
+    }
+
+    #[test]
+    fn test_parse_use_item_on_backward_compatible() {
+        let json = r#"{"at": 0, "do": "use_item_on", "pos": [0, 0, 0], "face": "top", "item": "minecraft:oak_slab"}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::UseItemOn {
+                hit: None,
+                hand: Hand::Main,
+                sneaking: false,
+                ..
+            }
+        ));
+
+        let json = r#"{
+            "at": 0,
+            "do": "use_item_on",
+            "pos": [0, 0, 0],
+            "face": "north",
+            "hit": [0.5, 0.75, 0.0],
+            "hand": "off",
+            "sneaking": true
+        }"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::UseItemOn {
+                hit: Some([0.5, 0.75, 0.0]),
+                hand: Hand::Off,
+                sneaking: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_item_use_actions() {
+        let json = r#"{"at": 0, "do": "use_item", "item": "minecraft:bow", "ticks": 20}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::UseItem {
+                hand: Hand::Main,
+                ticks: 20,
+                item: Some(_)
+            }
+        ));
+
+        let json = r#"{
+            "at": 0,
+            "do": "use_item_on_entity",
+            "entity": "minecraft:sheep",
+            "pos": [1.5, 1, 1.5],
+            "item": "minecraft:shears"
+        }"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::UseItemOnEntity { entity: Some(_), hand: Hand::Main, tolerance, .. }
+                if tolerance == 0.5
+        ));
+
+        let json = r#"{"at": 0, "do": "attack_entity", "pos": [1.5, 1, 1.5]}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::AttackEntity {
+                entity: None,
+                item: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_player_state() {
+        let config: PlayerConfig = serde_json::from_str(
+            r#"{
+                "game_mode": "creative",
+                "health": 10,
+                "food": 6,
+                "effects": [{"id": "minecraft:haste", "amplifier": 1, "duration": 200}]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(config.game_mode, Some(GameMode::Creative));
+        assert_eq!(config.health, Some(10.0));
+        assert_eq!(config.food, Some(6));
+        assert_eq!(config.effects[0].amplifier, 1);
+        assert_eq!(config.effects[0].duration, Some(200));
+
+        let json = r#"{
+            "at": 0,
+            "do": "assert_player",
+            "game_mode": "adventure",
+            "health": {"max": 19},
+            "effects": [{"id": "minecraft:haste"}]
+        }"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::AssertPlayer {
+                game_mode: Some(GameMode::Adventure),
+                health: Some(NumberMatcher::Range { min: None, max: Some(_) }),
+                food: None,
+                ref effects,
+                ..
+            } if effects.len() == 1 && effects[0].amplifier == 0
+        ));
+    }
+
+    #[test]
+    fn test_parse_command_actions() {
+        let json = r#"{"at": 0, "do": "command", "command": "setblock ~1 ~ ~ minecraft:stone"}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::Command { ref command } if command.starts_with("setblock")
+        ));
+
+        let json = r#"{"at": 1, "do": "assert_command", "command": "data get block ~1 ~ ~", "output": "stone"}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::AssertCommand {
+                success: None,
+                output: Some(_),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_world_settings() {
+        let setup: SetupSpec = serde_json::from_str(
+            r#"{
+                "time": 13000,
+                "weather": "thunder",
+                "game_rules": {"doFireTick": false, "randomTickSpeed": 0}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(setup.time, Some(13000));
+        assert_eq!(setup.weather, Some(Weather::Thunder));
+        assert_eq!(
+            setup.game_rules.get("doFireTick"),
+            Some(&GameRuleValue::Bool(false))
+        );
+        assert_eq!(
+            setup.game_rules.get("randomTickSpeed"),
+            Some(&GameRuleValue::Int(0))
+        );
+
+        let json = r#"{"at": 5, "do": "set_game_rule", "rule": "doDaylightCycle", "value": true}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::SetGameRule {
+                value: GameRuleValue::Bool(true),
+                ..
+            }
+        ));
+
+        let json = r#"{"at": 5, "do": "set_weather", "weather": "rain"}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::SetWeather {
+                weather: Weather::Rain
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_world_config() {
+        let setup: SetupSpec =
+            serde_json::from_str(r#"{"world": {"seed": 42, "random_tick_speed": 0}}"#).unwrap();
+        assert_eq!(setup.world.seed, Some(42));
+        assert_eq!(setup.world.random_tick_speed, Some(0));
+
+        let setup: SetupSpec = serde_json::from_str("{}").unwrap();
+        assert_eq!(setup.world, WorldConfig::default());
+        assert!(setup.world.requested_options().is_empty());
+
+        let world: WorldConfig = serde_json::from_str(
+            r#"{
+                "dimension": "minecraft:the_nether",
+                "layers": [{"block": "minecraft:bedrock"}, {"block": "minecraft:stone", "height": 3}],
+                "min_y": -64,
+                "max_y": 319,
+                "difficulty": "hard"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(world.layers.as_ref().unwrap()[0].height, 1);
+        assert_eq!(world.difficulty, Some(Difficulty::Hard));
+        assert_eq!(
+            world.requested_options(),
+            vec![
+                WorldOption::Dimension,
+                WorldOption::Layers,
+                WorldOption::BuildHeight,
+                WorldOption::Difficulty
+            ]
+        );
+
+        let json = r#"{"at": 3, "do": "random_tick", "pos": [0, 1, 0]}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::RandomTick { pos: [0, 1, 0] }
+        ));
+    }
+
+    #[test]
+    fn test_parse_scheduled_ticks() {
+        let json = r#"{"at": 0, "do": "schedule_tick", "pos": [0, 1, 0], "delay": 2}"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::ScheduleTick {
+                delay: 2,
+                priority: 0,
+                target: None,
+                ..
+            }
+        ));
+
+        let json = r#"{
+            "at": 1,
+            "do": "assert_scheduled",
+            "pos": [0, 1, 0],
+            "target": "minecraft:repeater",
+            "delay": {"max": 2},
+            "priority": -1
+        }"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            entry.action_type,
+            ActionType::AssertScheduled {
+                target: Some(_),
+                delay: Some(_),
+                priority: Some(-1),
+                count: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_assert_events() {
+        let json = r#"{
+            "at": 4,
+            "do": "assert_events",
+            "region": [[0, 0, 0], [2, 2, 2]],
+            "events": [
+                {"kind": "block_update", "pos": [1, 1, 0]},
+                {"kind": "sound", "id": "minecraft:block.note_block.harp"}
+            ],
+            "ordered": true,
+            "absent": [{"kind": "game_event", "id": "minecraft:block_activate"}]
+        }"#;
+        let entry: TimelineEntry = serde_json::from_str(json).unwrap();
+        let ActionType::AssertEvents {
+            since,
+            until,
+            events,
+            ordered,
+            absent,
+            ..
+        } = entry.action_type
+        else {
+            panic!("expected assert_events");
+        };
+        assert_eq!(since, None);
+        assert_eq!(until, None);
+        assert!(ordered);
+        assert_eq!(events[0].kind, EventKind::BlockUpdate);
+        assert_eq!(events[0].id, None);
+        assert_eq!(events[1].pos, None);
+        assert_eq!(absent[0].kind, EventKind::GameEvent);
+    }
+
+    #[test]
+    fn test_named_players() {
+        let json = r#"{
+            "name": "trade",
+            "setup": {
+                "cleanup": {"region": [[0, 0, 0], [4, 4, 4]]},
+                "players": {
+                    "alice": {"position": [0.5, 1, 0.5]},
+                    "bob": {"inventory": {"hotbar1": {"id": "minecraft:emerald"}}}
+                }
+            },
+            "timeline": [
+                {"at": 0, "do": "select_hotbar", "slot": 2},
+                {"at": 0, "do": "jump", "player": "alice"},
+                {"at": 1, "do": "assert_slot", "player": "bob", "slot": "hotbar1", "item": "minecraft:emerald"}
+            ]
+        }"#;
+        let spec: TestSpec = serde_json::from_str(json).unwrap();
+        spec.validate(true).unwrap();
+        let players = &spec.setup.as_ref().unwrap().players;
+        assert_eq!(players.len(), 2);
+        assert_eq!(spec.timeline[0].player, None);
+        assert_eq!(spec.timeline[1].player.as_deref(), Some("alice"));
+
+        let mut unknown = spec.clone();
+        unknown.timeline[1].player = Some("carol".to_string());
+        assert!(unknown.validate(true).is_err());
+
+        let mut not_player_action = spec.clone();
+        not_player_action.timeline[0] = serde_json::from_str(
+            r#"{"at": 0, "do": "remove", "pos": [0, 0, 0], "player": "alice"}"#,
+        )
+        .unwrap();
+        assert!(not_player_action.validate(true).is_err());
+    }
+
+    #[test]
+    fn test_validate_build_height() {
+        let fits = spec(
+            r#"{"cleanup": {"region": [[0, 0, 0], [4, 4, 4]]}, "world": {"min_y": 0, "max_y": 4}}"#,
+            &[],
+        );
+        fits.validate(true).unwrap();
+
+        let inverted = spec(
+            r#"{"cleanup": {"region": [[0, 0, 0], [4, 4, 4]]}, "world": {"min_y": 4, "max_y": 0}}"#,
+            &[],
+        );
+        let err = inverted.validate(true).unwrap_err().to_string();
+        assert!(err.contains("min_y 4 is above max_y 0"), "{}", err);
+
+        let too_low = spec(
+            r#"{"cleanup": {"region": [[0, 0, 0], [4, 4, 4]]}, "world": {"min_y": 1}}"#,
+            &[],
+        );
+        let err = too_low.validate(true).unwrap_err().to_string();
+        assert!(err.contains("Y range 0..=4 is outside"), "{}", err);
+        let too_high = spec(
+            r#"{"cleanup": {"region": [[0, 0, 0], [4, 4, 4]]}, "world": {"max_y": 3}}"#,
+            &[],
+        );
+        assert!(too_high.validate(true).is_err());
+
+        // Unordered corners are still checked when the cleanup region itself is not validated
+        let unordered_low = spec(
+            r#"{"cleanup": {"region": [[0, 4, 0], [4, 0, 4]]}, "world": {"min_y": 1}}"#,
+            &[],
+        );
+        let err = unordered_low.validate(false).unwrap_err().to_string();
+        assert!(err.contains("Y range 0..=4 is outside"), "{}", err);
+        let unordered_high = spec(
+            r#"{"cleanup": {"region": [[0, 4, 0], [4, 0, 4]]}, "world": {"max_y": 3}}"#,
+            &[],
+        );
+        assert!(unordered_high.validate(false).is_err());
+    }
+
+    #[test]
+    fn test_validate_strong_power_needs_face() {
+        let setup = r#"{"cleanup": {"region": [[0, 0, 0], [4, 4, 4]]}}"#;
+        let strong = r#"{"at": 0, "do": "assert_power", "pos": [0, 0, 0], "face": "top", "strong": true, "power": 15}"#;
+        spec(setup, &[strong]).validate(true).unwrap();
+
+        let no_face =
+            r#"{"at": 0, "do": "assert_power", "pos": [0, 0, 0], "strong": true, "power": 15}"#;
+        let err = spec(setup, &[no_face])
+            .validate(true)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("needs a 'face'"), "{}", err);
+    }
+
+    #[test]
+    fn test_validate_events_range() {
+        let setup = r#"{"cleanup": {"region": [[0, 0, 0], [4, 4, 4]]}}"#;
+        let events = |range: &str| {
+            let check = format!(
+                r#"{{"at": 5, "do": "assert_events", "region": [[4, 4, 4], [0, 0, 0]], {}}}"#,
+                range
+            );
+            spec(setup, &[&check]).validate(true)
+        };
+        events(r#""since": 2, "until": 2"#).unwrap();
+        let err = events(r#""since": 3, "until": 2"#).unwrap_err().to_string();
+        assert!(err.contains("'since' 3 is after 'until' 2"), "{}", err);
+    }
+
+    #[test]
+    fn test_validate_placed_tag() {
+        let setup = r#"{"cleanup": {"region": [[0, 0, 0], [4, 4, 4]]}}"#;
+        for place in [
+            r##"{"at": 0, "do": "place", "pos": [0, 0, 0], "block": {"id": "#minecraft:logs"}}"##,
+            r##"{"at": 0, "do": "place_each", "blocks": [{"pos": [0, 0, 0], "block": {"id": "#minecraft:logs"}}]}"##,
+            r##"{"at": 0, "do": "fill", "region": [[0, 0, 0], [1, 0, 0]], "with": {"id": "#minecraft:logs"}}"##,
+        ] {
+            let err = spec(setup, &[place])
+                .validate(true)
+                .unwrap_err()
+                .to_string();
+            assert!(
+                err.contains("Cannot place block tag '#minecraft:logs'"),
+                "{}",
+                err
+            );
+        }
+
+        let check = r##"{"at": 0, "do": "assert", "checks": [{"pos": [0, 0, 0], "is": {"id": "#minecraft:logs"}}]}"##;
+        spec(setup, &[check]).validate(true).unwrap();
+    }
+
+    #[test]
+    fn test_item_command_string() {
+        assert_eq!(Item::new("minecraft:stone").to_command(), "minecraft:stone");
+        assert_eq!(
+            Item::with_count("minecraft:stone", 3).to_command(),
+            "minecraft:stone 3"
+        );
+        assert_eq!(Item::empty().to_command(), "empty");
+    }
+
+    #[test]
+    fn test_block_tag() {
+        let logs = Block::new("#minecraft:logs");
+        assert!(logs.is_tag());
+        assert_eq!(logs.tag(), Some("minecraft:logs".to_string()));
+
+        let wool = Block::new("#wool");
+        assert_eq!(wool.tag(), Some("minecraft:wool".to_string()));
+
+        let stone = Block::new("minecraft:stone");
+        assert!(!stone.is_tag());
+        assert_eq!(stone.tag(), None);
+    }
 }
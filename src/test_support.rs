@@ -0,0 +1,241 @@
+//! Stubs and fixtures shared by the unit tests.
+//!
+//! `StubWorld` and `StubPlayer` implement only the required trait methods. Tests for an
+//! optional capability wrap them in a small purpose-named stub next to the tests, using
+//! `delegate_world!` and `delegate_player!` for the required methods.
+
+use crate::Block;
+use crate::results::{AssertFailure, AssertionResult, TestResult};
+use crate::runner::TestRunner;
+use crate::test_spec::{BlockFace, Item, PlayerSlot, TestSpec, WorldConfig, WorldOption};
+use crate::traits::{BlockPos, FlintAdapter, FlintPlayer, FlintWorld, Interaction, ServerInfo};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+/// Test spec named "test" with the given setup and timeline entries, all as JSON
+pub(crate) fn spec(setup: &str, timeline: &[&str]) -> TestSpec {
+    let json = format!(
+        r#"{{"name": "test", "setup": {}, "timeline": [{}]}}"#,
+        setup,
+        timeline.join(", ")
+    );
+    serde_json::from_str(&json).unwrap()
+}
+
+/// Run a test against a fresh copy of `world`
+pub(crate) fn run(world: impl FlintWorld + Clone + 'static, spec: &TestSpec) -> TestResult {
+    Arc::new(StubAdapter::new(world)).run(spec)
+}
+
+/// First failed assertion
+pub(crate) fn failure(result: &TestResult) -> Option<&AssertFailure> {
+    result
+        .assertions
+        .iter()
+        .find_map(|assertion| match assertion {
+            AssertionResult::Failure(fail) => Some(fail),
+            AssertionResult::Success(_) => None,
+        })
+}
+
+/// Error message of the first failed assertion
+pub(crate) fn failure_message(result: &TestResult) -> Option<&str> {
+    failure(result).map(|fail| fail.error_message.as_str())
+}
+
+/// Adapter creating a copy of a template world for every test
+pub(crate) struct StubAdapter {
+    world: Box<dyn Fn() -> Box<dyn FlintWorld> + Send + Sync>,
+    options: Vec<WorldOption>,
+    tags: BTreeMap<String, Vec<String>>,
+    /// Config of every created world, in creation order
+    pub configs: Mutex<Vec<WorldConfig>>,
+}
+
+impl StubAdapter {
+    /// Adapter without world options and block tags
+    pub fn new<W: FlintWorld + Clone + 'static>(world: W) -> Self {
+        Self {
+            world: Box::new(move || Box::new(world.clone())),
+            options: Vec::new(),
+            tags: BTreeMap::new(),
+            configs: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Accept the given world options
+    pub fn with_options(mut self, options: &[WorldOption]) -> Self {
+        self.options.extend_from_slice(options);
+        self
+    }
+
+    /// Resolve the block `tag` to `ids`
+    pub fn with_tag(mut self, tag: &str, ids: &[&str]) -> Self {
+        let ids = ids.iter().map(|id| id.to_string()).collect();
+        self.tags.insert(tag.to_string(), ids);
+        self
+    }
+
+    pub fn run(self: &Arc<Self>, spec: &TestSpec) -> TestResult {
+        TestRunner::new(self.clone()).run_test(spec)
+    }
+}
+
+impl FlintAdapter for StubAdapter {
+    fn create_test_world(&self, config: &WorldConfig) -> Box<dyn FlintWorld> {
+        self.configs.lock().unwrap().push(config.clone());
+        (self.world)()
+    }
+
+    fn supports_world_option(&self, option: WorldOption) -> bool {
+        self.options.contains(&option)
+    }
+
+    fn server_info(&self) -> ServerInfo {
+        ServerInfo {
+            minecraft_version: "test".to_string(),
+        }
+    }
+
+    fn block_tag(&self, tag: &str) -> Option<Vec<String>> {
+        self.tags.get(tag).cloned()
+    }
+}
+
+/// World storing blocks in memory, it never ticks and supports no optional capability
+#[derive(Clone)]
+pub(crate) struct StubWorld {
+    pub blocks: BTreeMap<BlockPos, Block>,
+    /// Creates the players joining the world
+    pub new_player: Arc<dyn Fn() -> Box<dyn FlintPlayer> + Send + Sync>,
+}
+
+impl StubWorld {
+    /// World whose players are created by `new_player`
+    pub fn with_players(
+        new_player: impl Fn() -> Box<dyn FlintPlayer> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            blocks: BTreeMap::new(),
+            new_player: Arc::new(new_player),
+        }
+    }
+}
+
+impl Default for StubWorld {
+    fn default() -> Self {
+        Self::with_players(|| Box::new(StubPlayer::default()))
+    }
+}
+
+impl FlintWorld for StubWorld {
+    fn do_tick(&mut self) {}
+
+    fn current_tick(&self) -> u64 {
+        0
+    }
+
+    fn get_block(&self, pos: BlockPos) -> Block {
+        let block = self.blocks.get(&pos).cloned();
+        block.unwrap_or_else(|| Block::new("minecraft:air"))
+    }
+
+    fn set_block(&mut self, pos: BlockPos, block: &Block) {
+        self.blocks.insert(pos, block.clone());
+    }
+
+    fn create_player(&mut self) -> Box<dyn FlintPlayer> {
+        (self.new_player)()
+    }
+}
+
+/// Implement the required `FlintWorld` methods through a `base: StubWorld` field
+macro_rules! delegate_world {
+    () => {
+        fn do_tick(&mut self) {
+            self.base.do_tick()
+        }
+
+        fn current_tick(&self) -> u64 {
+            self.base.current_tick()
+        }
+
+        fn get_block(&self, pos: BlockPos) -> Block {
+            self.base.get_block(pos)
+        }
+
+        fn set_block(&mut self, pos: BlockPos, block: &Block) {
+            self.base.set_block(pos, block)
+        }
+
+        fn create_player(&mut self) -> Box<dyn FlintPlayer> {
+            self.base.create_player()
+        }
+    };
+}
+pub(crate) use delegate_world;
+
+/// Player with an in-memory inventory, it supports no optional capability
+#[derive(Clone)]
+pub(crate) struct StubPlayer {
+    pub slots: HashMap<PlayerSlot, Item>,
+    pub selected_hotbar: u8,
+}
+
+impl Default for StubPlayer {
+    fn default() -> Self {
+        Self {
+            slots: HashMap::new(),
+            selected_hotbar: 1,
+        }
+    }
+}
+
+impl FlintPlayer for StubPlayer {
+    fn set_slot(&mut self, slot: PlayerSlot, item: Option<&Item>) {
+        match item {
+            Some(item) => self.slots.insert(slot, item.clone()),
+            None => self.slots.remove(&slot),
+        };
+    }
+
+    fn get_slot(&self, slot: PlayerSlot) -> Option<Item> {
+        self.slots.get(&slot).cloned()
+    }
+
+    fn select_hotbar(&mut self, slot: u8) {
+        self.selected_hotbar = slot;
+    }
+
+    fn selected_hotbar(&self) -> u8 {
+        self.selected_hotbar
+    }
+
+    fn use_item_on(&mut self, _pos: BlockPos, _face: &BlockFace, _interaction: &Interaction) {}
+}
+
+/// Implement the required `FlintPlayer` methods through a `base: StubPlayer` field
+macro_rules! delegate_player {
+    () => {
+        fn set_slot(&mut self, slot: PlayerSlot, item: Option<&Item>) {
+            self.base.set_slot(slot, item)
+        }
+
+        fn get_slot(&self, slot: PlayerSlot) -> Option<Item> {
+            self.base.get_slot(slot)
+        }
+
+        fn select_hotbar(&mut self, slot: u8) {
+            self.base.select_hotbar(slot)
+        }
+
+        fn selected_hotbar(&self) -> u8 {
+            self.base.selected_hotbar()
+        }
+
+        fn use_item_on(&mut self, pos: BlockPos, face: &BlockFace, interaction: &Interaction) {
+            self.base.use_item_on(pos, face, interaction)
+        }
+    };
+}
+pub(crate) use delegate_player;
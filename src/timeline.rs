@@ -102,7 +102,7 @@ impl<'a> TimelineAggregate<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_spec::{ActionType, Block, BlockCheck, BlockSpec, TickSpec};
+    use crate::test_spec::{ActionType, Block, BlockCheck, BlockSpec, PlaceMode, TickSpec};
 
     fn create_test_spec(
         name: &str,
@@ -126,8 +126,10 @@ mod tests {
     fn test_single_test_aggregation() {
         let entry1 = TimelineEntry {
             at: TickSpec::Single(0),
+            player: None,
             action_type: ActionType::Place {
                 pos: [0, 0, 0],
+                mode: PlaceMode::Update,
                 block: Block {
                     id: "stone".to_string(),
                     properties: Default::default(),
@@ -136,8 +138,10 @@ mod tests {
         };
         let entry2 = TimelineEntry {
             at: TickSpec::Single(5),
+            player: None,
             action_type: ActionType::Place {
                 pos: [1, 0, 0],
+                mode: PlaceMode::Update,
                 block: Block {
                     id: "dirt".to_string(),
                     properties: Default::default(),
@@ -159,8 +163,10 @@ mod tests {
     fn test_multiple_tests_aggregation() {
         let entry1 = TimelineEntry {
             at: TickSpec::Single(0),
+            player: None,
             action_type: ActionType::Place {
                 pos: [0, 0, 0],
+                mode: PlaceMode::Update,
                 block: Block {
                     id: "stone".to_string(),
                     properties: Default::default(),
@@ -169,8 +175,10 @@ mod tests {
         };
         let entry2 = TimelineEntry {
             at: TickSpec::Single(10),
+            player: None,
             action_type: ActionType::Place {
                 pos: [1, 0, 0],
+                mode: PlaceMode::Update,
                 block: Block {
                     id: "dirt".to_string(),
                     properties: Default::default(),
@@ -195,6 +203,7 @@ mod tests {
     fn test_multiple_ticks_expansion() {
         let entry = TimelineEntry {
             at: TickSpec::Multiple(vec![0, 5, 10]),
+            player: None,
             action_type: ActionType::Assert {
                 checks: vec![BlockCheck {
                     pos: [0, 0, 0],
@@ -202,6 +211,7 @@ mod tests {
                         id: "minecraft:redstone_wire".to_string(),
                         properties: Default::default(),
                     }),
+                    data: None,
                 }],
             },
         };
@@ -234,8 +244,10 @@ mod tests {
     fn test_next_event_tick() {
         let entry1 = TimelineEntry {
             at: TickSpec::Single(5),
+            player: None,
             action_type: ActionType::Place {
                 pos: [0, 0, 0],
+                mode: PlaceMode::Update,
                 block: Block {
                     id: "stone".to_string(),
                     properties: Default::default(),
@@ -244,8 +256,10 @@ mod tests {
         };
         let entry2 = TimelineEntry {
             at: TickSpec::Single(15),
+            player: None,
             action_type: ActionType::Place {
                 pos: [1, 0, 0],
+                mode: PlaceMode::Update,
                 block: Block {
                     properties: Default::default(),
                     id: "dirt".to_string(),
@@ -1,14 +1,156 @@
 //! Core traits that server implementations must provide.
 //!
-//! Servers implement `FlintAdapter` to create test worlds, and `FlintWorld`/`FlintPlayer`
-//! to provide the actual block and player operations.
+//! Servers implement `FlintAdapter` to create test worlds, and `FlintWorld`/`FlintPlayer`/
+//! `FlintEntity` to provide the actual block, player and entity operations.
 
 use crate::Block;
-use crate::test_spec::{BlockFace, Item, PlayerSlot};
+use crate::nbt::Nbt;
+use crate::test_spec::{
+    BlockFace, EventKind, GameMode, GameRuleValue, Hand, Item, PlaceMode, PlayerSlot, StatusEffect,
+    Weather, WorldConfig, WorldOption,
+};
 
 /// Position in world coordinates [x, y, z]
 pub type BlockPos = [i32; 3];
 
+/// Exact entity position in world coordinates [x, y, z]
+pub type EntityPos = [f64; 3];
+
+/// Unique id of an entity within its world
+pub type EntityId = u64;
+
+/// Details of a player clicking a block face
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interaction {
+    /// Click position relative to the block's minimum corner (0.0-1.0 per axis)
+    pub hit: [f64; 3],
+    /// Hand holding the used item
+    pub hand: Hand,
+    /// Whether the player is sneaking
+    pub sneaking: bool,
+}
+
+impl Interaction {
+    /// Main hand click on the center of a face without sneaking
+    pub fn new(face: &BlockFace) -> Self {
+        Self {
+            hit: face.center(),
+            hand: Hand::Main,
+            sneaking: false,
+        }
+    }
+}
+
+/// Result of running a server command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandResult {
+    /// Whether the command succeeded (vanilla success count > 0)
+    pub success: bool,
+    /// Feedback text sent to the command source, one message per line
+    pub output: String,
+}
+
+/// Fluid at a block position, independent of how the adapter stores it
+/// (fluid block `level` property, waterlogged blocks or a separate fluid layer)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FluidState {
+    /// Still fluid id, e.g. "minecraft:water" (also for flowing water), "minecraft:empty" if none
+    pub id: String,
+    /// Fluid amount from 1 to 8, 8 for sources and falling fluid, 0 if empty
+    pub amount: u8,
+    /// Whether the fluid is falling (flowing down)
+    pub falling: bool,
+    /// Whether the fluid is a source
+    pub source: bool,
+}
+
+impl FluidState {
+    /// No fluid
+    pub fn empty() -> Self {
+        Self {
+            id: "minecraft:empty".to_string(),
+            amount: 0,
+            falling: false,
+            source: false,
+        }
+    }
+
+    /// A source of the given fluid
+    pub fn source(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            amount: 8,
+            falling: false,
+            source: true,
+        }
+    }
+
+    /// Whether there is no fluid
+    pub fn is_empty(&self) -> bool {
+        self.amount == 0 || self.id == "minecraft:empty"
+    }
+}
+
+/// Side effects of a block change, see `FlintWorld::set_block_with`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetBlockFlags {
+    /// Notify neighbors so they react (redstone, rails, observers, falling blocks)
+    pub neighbor_updates: bool,
+    /// Update the shape of neighbors and the placed block (wire connections, fences, stairs)
+    pub shape_updates: bool,
+}
+
+impl SetBlockFlags {
+    /// A normal block change, like `set_block`
+    pub const UPDATE: Self = Self {
+        neighbor_updates: true,
+        shape_updates: true,
+    };
+
+    /// Change only the block itself, neighbors and the block keep their current state
+    pub const SILENT: Self = Self {
+        neighbor_updates: false,
+        shape_updates: false,
+    };
+}
+
+impl Default for SetBlockFlags {
+    fn default() -> Self {
+        Self::UPDATE
+    }
+}
+
+impl From<PlaceMode> for SetBlockFlags {
+    fn from(mode: PlaceMode) -> Self {
+        match mode {
+            PlaceMode::Update => Self::UPDATE,
+            PlaceMode::Silent => Self::SILENT,
+        }
+    }
+}
+
+/// A pending scheduled block or fluid tick
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledTick {
+    /// Block or fluid the tick was scheduled for, e.g. "minecraft:repeater" or "minecraft:water"
+    pub target: String,
+    /// Game ticks until the scheduled tick runs
+    pub delay: u32,
+    /// Vanilla tick priority, -3 (extremely high) to 3 (extremely low)
+    pub priority: i32,
+}
+
+/// An event captured in a test world, see `FlintWorld::capture_events`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorldEvent {
+    /// World tick at which the event happened (as returned by `current_tick`)
+    pub tick: u64,
+    pub kind: EventKind,
+    pub pos: BlockPos,
+    /// Updated block, game event or sound id, e.g. "minecraft:note_block"
+    pub id: String,
+}
+
 /// Server metadata
 #[derive(Debug, Clone)]
 pub struct ServerInfo {
@@ -22,16 +164,34 @@ pub struct ServerInfo {
 /// Main adapter trait - server implements this to create test worlds
 pub trait FlintAdapter: Send + Sync {
     /// Create a new disposable in-memory test world
-    fn create_test_world(&self) -> Box<dyn FlintWorld>;
+    ///
+    /// Only options reported by `supports_world_option` are ever set in `config`,
+    /// the runner fails tests requesting anything else before creating a world.
+    fn create_test_world(&self, config: &WorldConfig) -> Box<dyn FlintWorld>;
+
+    /// Whether `create_test_world` honors a world option
+    ///
+    /// Optional: no options are supported by default.
+    fn supports_world_option(&self, _option: WorldOption) -> bool {
+        false
+    }
 
     /// Server metadata for logging
     fn server_info(&self) -> ServerInfo;
+
+    /// Block ids contained in a block tag, e.g. `minecraft:logs`
+    ///
+    /// The tag name is namespaced and given without the leading `#`.
+    /// Returns `None` if the tag is unknown or the adapter has no tag registry.
+    fn block_tag(&self, _tag: &str) -> Option<Vec<String>> {
+        None
+    }
 }
 
 /// World operations - server implements this
 ///
 /// This is the minimal interface servers must provide.
-/// Flint handles fill/clear by iterating `set_block()`.
+/// Flint handles fill/clear by iterating `set_block_with()`.
 pub trait FlintWorld: Send + Sync {
     /// Execute exactly one game tick
     fn do_tick(&mut self);
@@ -45,11 +205,240 @@ pub trait FlintWorld: Send + Sync {
     /// Set block at position (with neighbor updates)
     fn set_block(&mut self, pos: BlockPos, block: &Block);
 
+    /// Set block at position with control over neighbor and shape updates
+    ///
+    /// Optional: the default implementation only handles `SetBlockFlags::UPDATE`
+    /// through `set_block` and returns `false` for any other flags.
+    fn set_block_with(&mut self, pos: BlockPos, block: &Block, flags: SetBlockFlags) -> bool {
+        if flags == SetBlockFlags::UPDATE {
+            self.set_block(pos, block);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Get block entity data at position
+    ///
+    /// Optional: returns `None` if there is no block entity at the position
+    /// or the adapter does not support block entities.
+    fn get_block_data(&self, _pos: BlockPos) -> Option<Nbt> {
+        None
+    }
+
+    /// Merge data into the block entity at position, like `/data merge block`
+    ///
+    /// Optional: returns `false` if there is no block entity at the position
+    /// or the adapter does not support block entities.
+    fn set_block_data(&mut self, _pos: BlockPos, _data: &Nbt) -> bool {
+        false
+    }
+
+    /// Strong (direct) redstone signal the block at position emits through a face
+    ///
+    /// This is the power a solid block touching that face is strongly powered with.
+    /// Optional: returns `None` if the adapter does not support signal queries.
+    fn direct_signal(&self, _pos: BlockPos, _face: &BlockFace) -> Option<u8> {
+        None
+    }
+
+    /// Redstone signal the block at position receives through a face, including
+    /// power conducted through solid blocks (0-15)
+    ///
+    /// With `None`, the highest signal received through any face.
+    /// Optional: returns `None` if the adapter does not support signal queries.
+    fn indirect_signal(&self, _pos: BlockPos, _face: Option<&BlockFace>) -> Option<u8> {
+        None
+    }
+
+    /// Fluid at a position, including fluid inside waterlogged blocks
+    ///
+    /// Optional: returns `None` if the adapter does not support fluid queries.
+    fn get_fluid(&self, _pos: BlockPos) -> Option<FluidState> {
+        None
+    }
+
+    /// Place a fluid at a position (with neighbor updates so it starts flowing)
+    ///
+    /// Waterlogs the block at the position if it can hold the fluid,
+    /// otherwise replaces it with the fluid's block.
+    /// Optional: returns `false` if the fluid cannot be placed
+    /// or the adapter does not support fluid placement.
+    fn set_fluid(&mut self, _pos: BlockPos, _fluid: &FluidState) -> bool {
+        false
+    }
+
+    /// Set the biome of every block in a region (both corners inclusive)
+    ///
+    /// Vanilla stores biomes per 4x4x4 cell, so adapters may change whole cells like `/fillbiome`.
+    /// Optional: returns `false` if the biome is unknown or the adapter does not support biomes.
+    fn set_biome(&mut self, _region: [BlockPos; 2], _biome: &str) -> bool {
+        false
+    }
+
+    /// Biome id at a position, e.g. "minecraft:plains"
+    ///
+    /// Optional: returns `None` if the adapter does not support biomes.
+    fn get_biome(&self, _pos: BlockPos) -> Option<String> {
+        None
+    }
+
+    /// Block light level at a position (0-15), emitted by torches, glowstone, lava, ...
+    ///
+    /// Optional: returns `None` if the adapter does not support light queries.
+    fn block_light(&self, _pos: BlockPos) -> Option<u8> {
+        None
+    }
+
+    /// Sky light level at a position (0-15), independent of time and weather
+    ///
+    /// Optional: returns `None` if the adapter does not support light queries.
+    fn sky_light(&self, _pos: BlockPos) -> Option<u8> {
+        None
+    }
+
+    /// Number of slots of the container at position (chest, hopper, furnace, ...)
+    ///
+    /// Optional: returns `None` if there is no container at the position
+    /// or the adapter does not support containers.
+    fn container_size(&self, _pos: BlockPos) -> Option<usize> {
+        None
+    }
+
+    /// Get item from a container slot (None if empty)
+    ///
+    /// Slots are numbered from 0 like in vanilla, e.g. furnace input 0, fuel 1, result 2.
+    fn get_container_slot(&self, _pos: BlockPos, _slot: usize) -> Option<Item> {
+        None
+    }
+
+    /// Set item in a container slot (None = empty/clear the slot)
+    ///
+    /// Optional: returns `false` if there is no container at the position,
+    /// the slot is out of range or the adapter does not support containers.
+    fn set_container_slot(&mut self, _pos: BlockPos, _slot: usize, _item: Option<&Item>) -> bool {
+        false
+    }
+
     /// Create a simulated player in this world
     ///
     /// Only called when tests use `use_item_on` or player-related actions.
     /// Pure block tests (place, fill, assert) don't need a player.
     fn create_player(&mut self) -> Box<dyn FlintPlayer>;
+
+    /// Spawn an entity by id (e.g. `minecraft:zombie`) at a position, with optional entity data
+    ///
+    /// Optional: returns `None` if the entity could not be spawned
+    /// or the adapter does not support entities.
+    fn spawn_entity(
+        &mut self,
+        _entity_type: &str,
+        _pos: EntityPos,
+        _data: Option<&Nbt>,
+    ) -> Option<Box<dyn FlintEntity>> {
+        None
+    }
+
+    /// List entities whose position lies inside the block region (both corners inclusive)
+    ///
+    /// The region is always given as `[min, max]`.
+    /// Optional: returns `None` if the adapter does not support entities.
+    fn entities_in(&self, _region: [BlockPos; 2]) -> Option<Vec<Box<dyn FlintEntity>>> {
+        None
+    }
+
+    /// Remove an entity from the world
+    ///
+    /// Optional: returns `false` if the entity does not exist
+    /// or the adapter does not support entities.
+    fn remove_entity(&mut self, _id: EntityId) -> bool {
+        false
+    }
+
+    /// Start recording block updates, shape updates, game events and sounds inside a region
+    ///
+    /// Called by the runner before the first tick of tests that assert events.
+    /// Optional: returns `false` if the adapter does not support event capture.
+    fn capture_events(&mut self, _region: [BlockPos; 2]) -> bool {
+        false
+    }
+
+    /// Events recorded since `capture_events`, in the order they happened
+    ///
+    /// Optional: returns `None` if the adapter does not support event capture.
+    fn captured_events(&self) -> Option<Vec<WorldEvent>> {
+        None
+    }
+
+    /// Pending scheduled ticks (block and fluid) at a position, in execution order
+    ///
+    /// Optional: returns `None` if the adapter does not support scheduled ticks.
+    fn scheduled_ticks(&self, _pos: BlockPos) -> Option<Vec<ScheduledTick>> {
+        None
+    }
+
+    /// Schedule a block or fluid tick at a position
+    ///
+    /// Optional: returns `false` if the adapter does not support scheduled ticks.
+    fn schedule_tick(&mut self, _pos: BlockPos, _tick: &ScheduledTick) -> bool {
+        false
+    }
+
+    /// Run the random tick behavior of the block at a position once
+    ///
+    /// Optional: returns `false` if the adapter does not support forced random ticks.
+    fn random_tick(&mut self, _pos: BlockPos) -> bool {
+        false
+    }
+
+    /// Set the time of day in ticks (0 = sunrise, 6000 = noon, 13000 = night)
+    ///
+    /// Optional: returns `false` if the adapter does not support world time.
+    fn set_day_time(&mut self, _time: u64) -> bool {
+        false
+    }
+
+    /// Current time of day in ticks, `None` if the adapter does not support world time
+    fn day_time(&self) -> Option<u64> {
+        None
+    }
+
+    /// Set the weather, lasting until changed again
+    ///
+    /// Optional: returns `false` if the adapter does not support weather.
+    fn set_weather(&mut self, _weather: Weather) -> bool {
+        false
+    }
+
+    /// Current weather, `None` if the adapter does not support weather
+    fn weather(&self) -> Option<Weather> {
+        None
+    }
+
+    /// Set a game rule by its vanilla name, e.g. `doFireTick`
+    ///
+    /// Optional: returns `false` if the rule is unknown, the value has the wrong type
+    /// or the adapter does not support game rules.
+    fn set_game_rule(&mut self, _rule: &str, _value: &GameRuleValue) -> bool {
+        false
+    }
+
+    /// Current value of a game rule, `None` if unknown or game rules are unsupported
+    ///
+    /// Used by the runner to restore rules changed by a test.
+    fn game_rule(&self, _rule: &str) -> Option<GameRuleValue> {
+        None
+    }
+
+    /// Run a command (without leading `/`) as the server with operator permissions
+    ///
+    /// The runner wraps test commands in `execute positioned <test origin> run ...`,
+    /// so `~` coordinates resolve relative to the test origin while absolute
+    /// coordinates are passed through unchanged.
+    /// Optional: returns `None` if the adapter does not support commands.
+    fn execute_command(&mut self, _command: &str) -> Option<CommandResult> {
+        None
+    }
 }
 
 /// Player operations - server implements this
@@ -57,12 +446,16 @@ pub trait FlintWorld: Send + Sync {
 /// Hybrid model: Server owns the player entity, but flint can:
 /// - Manipulate inventory slots directly
 /// - Select hotbar slots
-/// - Trigger item use actions
+/// - Trigger item use and block breaking actions
 pub trait FlintPlayer: Send + Sync {
     /// Set item in a slot (None = empty/clear the slot)
+    ///
+    /// Adapters should apply the item's components (enchantments, damage, custom name, ...).
     fn set_slot(&mut self, slot: PlayerSlot, item: Option<&Item>);
 
     /// Get item from a slot (None if empty)
+    ///
+    /// Adapters should report the item's components so assertions can match them.
     fn get_slot(&self, slot: PlayerSlot) -> Option<Item>;
 
     /// Select which hotbar slot is active (1-9)
@@ -71,8 +464,174 @@ pub trait FlintPlayer: Send + Sync {
     /// Get currently selected hotbar slot (1-9)
     fn selected_hotbar(&self) -> u8;
 
-    /// Use the item in the active hotbar slot on a block face
+    /// Use the item in the given hand on a block face
+    ///
+    /// This tests the server's actual interaction logic. The interaction carries the exact
+    /// click position (slab halves, stair orientation), the hand and the sneaking state.
+    fn use_item_on(&mut self, pos: BlockPos, face: &BlockFace, interaction: &Interaction);
+
+    /// Break a block immediately as this player, using the held item as tool
+    ///
+    /// Runs the server's player break logic (drops, tool durability, multi-block
+    /// blocks like beds and doors) without waiting for the mining time.
+    /// Returns whether the block was broken, `Some(false)` e.g. for unbreakable blocks.
+    /// Optional: returns `None` if the adapter does not support breaking blocks.
+    fn break_block(&mut self, _pos: BlockPos) -> Option<bool> {
+        None
+    }
+
+    /// Start mining a block like a survival player holding left click
+    ///
+    /// The adapter advances mining progress on every `FlintWorld::do_tick` based on the
+    /// held tool and breaks the block once mining completes.
+    /// Returns whether mining started, `Some(false)` e.g. for unbreakable blocks.
+    /// Optional: returns `None` if the adapter does not support progressive mining.
+    fn start_mining(&mut self, _pos: BlockPos, _face: &BlockFace) -> Option<bool> {
+        None
+    }
+
+    /// Use the item in a hand without targeting a block (eat, drink, throw, draw a bow)
+    ///
+    /// The adapter keeps the item in use for `hold_ticks` calls of `FlintWorld::do_tick`
+    /// and then releases it, so charged items (bows, tridents) fire on release.
+    /// `hold_ticks = 0` is a single click (ender pearl, snowball).
+    /// Optional: returns `false` if the adapter does not support using items.
+    fn use_item(&mut self, _hand: Hand, _hold_ticks: u32) -> bool {
+        false
+    }
+
+    /// Right click an entity with the item in a hand (shear sheep, milk cow, trade)
+    ///
+    /// Optional: returns `false` if the entity does not exist
+    /// or the adapter does not support entity interaction.
+    fn use_item_on_entity(&mut self, _entity: EntityId, _hand: Hand) -> bool {
+        false
+    }
+
+    /// Attack an entity with the item in the main hand
+    ///
+    /// Optional: returns `false` if the entity does not exist
+    /// or the adapter does not support entity interaction.
+    fn attack_entity(&mut self, _entity: EntityId) -> bool {
+        false
+    }
+
+    /// Set the player game mode
+    ///
+    /// Optional: returns `false` if the adapter does not support game modes.
+    fn set_game_mode(&mut self, _mode: GameMode) -> bool {
+        false
+    }
+
+    /// Current game mode, `None` if the adapter does not support game modes
+    fn game_mode(&self) -> Option<GameMode> {
+        None
+    }
+
+    /// Set the player health (20 = full)
+    ///
+    /// Optional: returns `false` if the adapter does not support player health.
+    fn set_health(&mut self, _health: f32) -> bool {
+        false
+    }
+
+    /// Current health, `None` if the adapter does not support player health
+    fn health(&self) -> Option<f32> {
+        None
+    }
+
+    /// Set the player food level (0-20)
+    ///
+    /// Optional: returns `false` if the adapter does not support hunger.
+    fn set_food_level(&mut self, _food: u8) -> bool {
+        false
+    }
+
+    /// Current food level, `None` if the adapter does not support hunger
+    fn food_level(&self) -> Option<u8> {
+        None
+    }
+
+    /// Apply a status effect, replacing an existing effect of the same type
+    ///
+    /// Optional: returns `false` if the effect is unknown
+    /// or the adapter does not support status effects.
+    fn add_effect(&mut self, _effect: &StatusEffect) -> bool {
+        false
+    }
+
+    /// Active status effects, `None` if the adapter does not support status effects
+    fn effects(&self) -> Option<Vec<StatusEffect>> {
+        None
+    }
+
+    /// Teleport the player to an exact position and rotation (degrees, vanilla convention)
+    ///
+    /// Optional: returns `false` if the adapter does not support player positioning.
+    fn teleport(&mut self, _pos: EntityPos, _yaw: f32, _pitch: f32) -> bool {
+        false
+    }
+
+    /// Current player position (feet)
+    ///
+    /// Optional: returns `None` if the adapter does not support player positioning.
+    fn position(&self) -> Option<EntityPos> {
+        None
+    }
+
+    /// Whether the player is standing on the ground
+    ///
+    /// Optional: returns `None` if the adapter does not support player positioning.
+    fn on_ground(&self) -> Option<bool> {
+        None
+    }
+
+    /// Move the player by a delta with collisions, like walking
+    ///
+    /// Optional: returns `false` if the adapter does not support player movement.
+    fn move_by(&mut self, _delta: EntityPos) -> bool {
+        false
+    }
+
+    /// Make the player jump (only takes effect when on the ground)
+    ///
+    /// Optional: returns `false` if the adapter does not support player movement.
+    fn jump(&mut self) -> bool {
+        false
+    }
+}
+
+/// Entity handle - server implements this
+///
+/// Server owns the entity; the handle reports its current state when queried.
+pub trait FlintEntity: Send + Sync {
+    /// Unique id of this entity within its world
+    fn id(&self) -> EntityId;
+
+    /// Entity type, e.g. `minecraft:item`
+    fn entity_type(&self) -> String;
+
+    /// Current position in world coordinates
+    fn position(&self) -> EntityPos;
+
+    /// Entity data (NBT), e.g. `{"Item": {"id": "minecraft:stone", "count": 1}}` for item entities
+    ///
+    /// Optional: returns `None` if the adapter does not expose entity data.
+    fn data(&self) -> Option<Nbt> {
+        None
+    }
+
+    /// Item stack carried by an item entity
+    ///
+    /// Defaults to reading the `Item` compound of the entity data.
+    fn item(&self) -> Option<Item> {
+        self.data()?.get("Item").and_then(Item::from_nbt)
+    }
+
+    /// World tick (as in `FlintWorld::current_tick`) at which this entity was spawned
     ///
-    /// This tests the server's actual interaction logic.
-    fn use_item_on(&mut self, pos: BlockPos, face: &BlockFace);
+    /// Optional: returns `None` if the adapter does not track spawn ticks.
+    fn spawn_tick(&self) -> Option<u64> {
+        None
+    }
 }